@@ -0,0 +1,155 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::amd_pstate::policy::{self, path, Epp, Values};
+use crate::cpu::CoreType;
+use crate::util::cell::Cell;
+use crate::{Error, Result};
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    id: u64,
+    amd_pstate_highest_perf: Cell<u64>,
+    amd_pstate_hw_prefcore: Cell<bool>,
+    amd_pstate_lowest_nonlinear_freq: Cell<u64>,
+    amd_pstate_max_freq: Cell<u64>,
+    amd_pstate_prefcore_ranking: Cell<u64>,
+    energy_performance_preference: Cell<String>,
+    energy_performance_available_preferences: Cell<Vec<String>>,
+}
+
+impl Cache {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        policy::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        policy::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        policy::ids()
+    }
+
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        policy::ids_for_core_type(core_type)
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::housekeeping_ids()
+    }
+
+    pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::non_housekeeping_ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        policy::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            amd_pstate_highest_perf: Cell::default(),
+            amd_pstate_hw_prefcore: Cell::default(),
+            amd_pstate_lowest_nonlinear_freq: Cell::default(),
+            amd_pstate_max_freq: Cell::default(),
+            amd_pstate_prefcore_ranking: Cell::default(),
+            energy_performance_preference: Cell::default(),
+            energy_performance_available_preferences: Cell::default(),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(
+            self.amd_pstate_highest_perf.clear(),
+            self.amd_pstate_hw_prefcore.clear(),
+            self.amd_pstate_lowest_nonlinear_freq.clear(),
+            self.amd_pstate_max_freq.clear(),
+            self.amd_pstate_prefcore_ranking.clear(),
+            self.energy_performance_preference.clear(),
+            self.energy_performance_available_preferences.clear(),
+        );
+    }
+
+    pub async fn amd_pstate_highest_perf(&self) -> Result<u64> {
+        self.amd_pstate_highest_perf
+            .get_or_load(policy::amd_pstate_highest_perf(self.id))
+            .await
+    }
+
+    pub async fn amd_pstate_hw_prefcore(&self) -> Result<bool> {
+        self.amd_pstate_hw_prefcore
+            .get_or_load(policy::amd_pstate_hw_prefcore(self.id))
+            .await
+    }
+
+    pub async fn amd_pstate_lowest_nonlinear_freq(&self) -> Result<u64> {
+        self.amd_pstate_lowest_nonlinear_freq
+            .get_or_load(policy::amd_pstate_lowest_nonlinear_freq(self.id))
+            .await
+    }
+
+    pub async fn amd_pstate_max_freq(&self) -> Result<u64> {
+        self.amd_pstate_max_freq
+            .get_or_load(policy::amd_pstate_max_freq(self.id))
+            .await
+    }
+
+    pub async fn amd_pstate_prefcore_ranking(&self) -> Result<u64> {
+        self.amd_pstate_prefcore_ranking
+            .get_or_load(policy::amd_pstate_prefcore_ranking(self.id))
+            .await
+    }
+
+    pub async fn epp(&self) -> Result<Epp> {
+        let v = self.energy_performance_preference().await?;
+        v.parse::<Epp>().map_err(|_| {
+            let path = path::energy_performance_preference(self.id);
+            Error::sysfs_parse(path, "Epp", v)
+        })
+    }
+
+    pub async fn energy_performance_preference(&self) -> Result<String> {
+        self.energy_performance_preference
+            .get_or_load(policy::energy_performance_preference(self.id))
+            .await
+    }
+
+    pub async fn energy_performance_available_preferences(&self) -> Result<Vec<String>> {
+        self.energy_performance_available_preferences
+            .get_or_load(policy::energy_performance_available_preferences(self.id))
+            .await
+    }
+
+    pub async fn set_epp(&self, v: Epp) -> Result<()> {
+        self.energy_performance_preference
+            .clear_if_ok(policy::set_epp(self.id, v))
+            .await
+    }
+
+    pub async fn set_energy_performance_preference(&self, v: impl AsRef<str>) -> Result<()> {
+        self.energy_performance_preference
+            .clear_if_ok(policy::set_energy_performance_preference(
+                self.id,
+                v.as_ref(),
+            ))
+            .await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(v: Values) -> Self {
+        Self::new(v.id())
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(v: &Values) -> Self {
+        Self::new(v.id())
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(feature = "cache")]
+pub mod cache;
+pub(crate) mod path;
+pub mod values;
+
+pub use crate::amd_pstate::available;
+#[cfg(feature = "cache")]
+pub use crate::amd_pstate::policy::cache::Cache;
+pub use crate::amd_pstate::policy::values::Values;
+pub use crate::cpufreq::{exists, housekeeping_ids, ids, ids_for_core_type, non_housekeeping_ids};
+pub use crate::intel_pstate::policy::Epp;
+use crate::util::sysfs;
+use crate::{Error, Result};
+
+pub async fn amd_pstate_highest_perf(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::amd_pstate_highest_perf(id)).await
+}
+
+pub async fn amd_pstate_hw_prefcore(id: u64) -> Result<bool> {
+    sysfs::read_bool(&path::amd_pstate_hw_prefcore(id)).await
+}
+
+pub async fn amd_pstate_lowest_nonlinear_freq(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::amd_pstate_lowest_nonlinear_freq(id)).await
+}
+
+pub async fn amd_pstate_max_freq(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::amd_pstate_max_freq(id)).await
+}
+
+pub async fn amd_pstate_prefcore_ranking(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::amd_pstate_prefcore_ranking(id)).await
+}
+
+pub async fn epp(id: u64) -> Result<Epp> {
+    let path = path::energy_performance_preference(id);
+    let v = sysfs::read_string(&path).await?;
+    v.parse::<Epp>()
+        .map_err(|_| Error::sysfs_parse(&path, "Epp", v))
+}
+
+pub async fn energy_performance_preference(id: u64) -> Result<String> {
+    sysfs::read_string(&path::energy_performance_preference(id)).await
+}
+
+pub async fn energy_performance_available_preferences(id: u64) -> Result<Vec<String>> {
+    sysfs::read_string_list(&path::energy_performance_available_preferences(id), ' ').await
+}
+
+pub async fn set_energy_performance_preference(id: u64, v: &str) -> Result<()> {
+    sysfs::write_string(&path::energy_performance_preference(id), v).await
+}
+
+// amd-pstate only accepts the named preferences, not raw values.
+pub async fn set_epp(id: u64, v: Epp) -> Result<()> {
+    if let Epp::Raw(v) = v {
+        let s = format!("amd_pstate does not accept raw epp values: {}", v);
+        return Err(Error::non_sequitor(s));
+    }
+    set_energy_performance_preference(id, &v.to_string()).await
+}
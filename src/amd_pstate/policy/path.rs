@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use crate::cpufreq::path::policy_attr;
+
+pub(crate) fn amd_pstate_highest_perf(id: u64) -> PathBuf {
+    policy_attr(id, "amd_pstate_highest_perf")
+}
+
+pub(crate) fn amd_pstate_hw_prefcore(id: u64) -> PathBuf {
+    policy_attr(id, "amd_pstate_hw_prefcore")
+}
+
+pub(crate) fn amd_pstate_lowest_nonlinear_freq(id: u64) -> PathBuf {
+    policy_attr(id, "amd_pstate_lowest_nonlinear_freq")
+}
+
+pub(crate) fn amd_pstate_max_freq(id: u64) -> PathBuf {
+    policy_attr(id, "amd_pstate_max_freq")
+}
+
+pub(crate) fn amd_pstate_prefcore_ranking(id: u64) -> PathBuf {
+    policy_attr(id, "amd_pstate_prefcore_ranking")
+}
+
+pub(crate) fn energy_performance_preference(id: u64) -> PathBuf {
+    policy_attr(id, "energy_performance_preference")
+}
+
+pub(crate) fn energy_performance_available_preferences(id: u64) -> PathBuf {
+    policy_attr(id, "energy_performance_available_preferences")
+}
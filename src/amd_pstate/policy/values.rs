@@ -0,0 +1,107 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::amd_pstate::policy::{self, Epp};
+#[cfg(feature = "cache")]
+use crate::amd_pstate::policy::Cache;
+use crate::cpu::CoreType;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values {
+    id: u64,
+}
+
+impl Values {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        policy::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        policy::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        policy::ids()
+    }
+
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        policy::ids_for_core_type(core_type)
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::housekeeping_ids()
+    }
+
+    pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::non_housekeeping_ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        policy::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn amd_pstate_highest_perf(&self) -> impl Future<Output = Result<u64>> {
+        policy::amd_pstate_highest_perf(self.id)
+    }
+
+    pub fn amd_pstate_hw_prefcore(&self) -> impl Future<Output = Result<bool>> {
+        policy::amd_pstate_hw_prefcore(self.id)
+    }
+
+    pub fn amd_pstate_lowest_nonlinear_freq(&self) -> impl Future<Output = Result<u64>> {
+        policy::amd_pstate_lowest_nonlinear_freq(self.id)
+    }
+
+    pub fn amd_pstate_max_freq(&self) -> impl Future<Output = Result<u64>> {
+        policy::amd_pstate_max_freq(self.id)
+    }
+
+    pub fn amd_pstate_prefcore_ranking(&self) -> impl Future<Output = Result<u64>> {
+        policy::amd_pstate_prefcore_ranking(self.id)
+    }
+
+    pub fn epp(&self) -> impl Future<Output = Result<Epp>> {
+        policy::epp(self.id)
+    }
+
+    pub fn energy_performance_preference(&self) -> impl Future<Output = Result<String>> {
+        policy::energy_performance_preference(self.id)
+    }
+
+    pub fn energy_performance_available_preferences(
+        &self,
+    ) -> impl Future<Output = Result<Vec<String>>> {
+        policy::energy_performance_available_preferences(self.id)
+    }
+
+    pub fn set_epp(&self, v: Epp) -> impl Future<Output = Result<()>> {
+        policy::set_epp(self.id, v)
+    }
+
+    pub async fn set_energy_performance_preference(&self, v: impl AsRef<str>) -> Result<()> {
+        policy::set_energy_performance_preference(self.id, v.as_ref()).await
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<Cache> for Values {
+    fn from(v: Cache) -> Self {
+        Self::new(v.id())
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<&Cache> for Values {
+    fn from(v: &Cache) -> Self {
+        Self::new(v.id())
+    }
+}
@@ -0,0 +1,56 @@
+use futures::Future;
+
+use crate::amd_pstate::system::{self, Values};
+use crate::util::cell::Cell;
+use crate::Result;
+
+#[derive(Clone, Debug, Default)]
+pub struct Cache {
+    prefcore: Cell<String>,
+    status: Cell<String>,
+}
+
+impl Cache {
+    pub const ACTIVE: &'static str = crate::amd_pstate::system::ACTIVE;
+    pub const DISABLE: &'static str = crate::amd_pstate::system::DISABLE;
+    pub const GUIDED: &'static str = crate::amd_pstate::system::GUIDED;
+    pub const PASSIVE: &'static str = crate::amd_pstate::system::PASSIVE;
+
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        system::available()
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(self.prefcore.clear(), self.status.clear());
+    }
+
+    pub async fn prefcore(&self) -> Result<String> {
+        self.prefcore.get_or_load(system::prefcore()).await
+    }
+
+    pub async fn status(&self) -> Result<String> {
+        self.status.get_or_load(system::status()).await
+    }
+
+    pub async fn is_active(&self) -> Result<bool> {
+        self.status().await.map(|v| v == system::ACTIVE)
+    }
+
+    pub async fn set_status(&self, v: impl AsRef<str>) -> Result<()> {
+        self.status
+            .clear_if_ok(system::set_status(v.as_ref()))
+            .await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(_: Values) -> Self {
+        Self::default()
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(_: &Values) -> Self {
+        Self::default()
+    }
+}
@@ -0,0 +1,28 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub(crate) mod path;
+mod values;
+
+pub use crate::amd_pstate::available;
+#[cfg(feature = "cache")]
+pub use crate::amd_pstate::system::cache::Cache;
+pub use crate::amd_pstate::system::values::Values;
+use crate::util::sysfs;
+use crate::Result;
+
+pub const ACTIVE: &str = "active";
+pub const PASSIVE: &str = "passive";
+pub const GUIDED: &str = "guided";
+pub const DISABLE: &str = "disable";
+
+pub async fn prefcore() -> Result<String> {
+    sysfs::read_string(&path::prefcore()).await
+}
+
+pub async fn status() -> Result<String> {
+    sysfs::read_string(&path::status()).await
+}
+
+pub async fn set_status(v: &str) -> Result<()> {
+    sysfs::write_string(&path::status(), v).await
+}
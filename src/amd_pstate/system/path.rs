@@ -0,0 +1,17 @@
+use std::path::PathBuf;
+
+pub(crate) fn root() -> PathBuf {
+    PathBuf::from("/sys/devices/system/cpu/amd_pstate")
+}
+
+pub(crate) fn root_attr(a: &str) -> PathBuf {
+    root().join(a)
+}
+
+pub(crate) fn prefcore() -> PathBuf {
+    root_attr("prefcore")
+}
+
+pub(crate) fn status() -> PathBuf {
+    root_attr("status")
+}
@@ -0,0 +1,50 @@
+use futures::Future;
+
+use crate::amd_pstate::system;
+#[cfg(feature = "cache")]
+use crate::amd_pstate::system::Cache;
+use crate::Result;
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values;
+
+impl Values {
+    pub const ACTIVE: &'static str = crate::amd_pstate::system::ACTIVE;
+    pub const DISABLE: &'static str = crate::amd_pstate::system::DISABLE;
+    pub const GUIDED: &'static str = crate::amd_pstate::system::GUIDED;
+    pub const PASSIVE: &'static str = crate::amd_pstate::system::PASSIVE;
+
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        system::available()
+    }
+
+    pub fn prefcore(&self) -> impl Future<Output = Result<String>> {
+        system::prefcore()
+    }
+
+    pub fn status(&self) -> impl Future<Output = Result<String>> {
+        system::status()
+    }
+
+    pub async fn is_active(&self) -> Result<bool> {
+        self.status().await.map(|v| v == system::ACTIVE)
+    }
+
+    pub async fn set_status(&self, v: impl AsRef<str>) -> Result<()> {
+        system::set_status(v.as_ref()).await
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<Cache> for Values {
+    fn from(_: Cache) -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<&Cache> for Values {
+    fn from(_: &Cache) -> Self {
+        Self
+    }
+}
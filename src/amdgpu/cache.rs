@@ -0,0 +1,213 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::amdgpu::{self, DpmLevel, PowerProfile, Values};
+use crate::util::cell::Cell;
+use crate::Result;
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    id: u64,
+    gpu_busy_percent: Cell<u64>,
+    mem_info_vis_vram_total: Cell<u64>,
+    mem_info_vis_vram_used: Cell<u64>,
+    mem_info_vram_total: Cell<u64>,
+    mem_info_vram_used: Cell<u64>,
+    power_dpm_force_performance_level: Cell<String>,
+    power1_cap: Cell<u64>,
+    power1_cap_max: Cell<u64>,
+    pp_dpm_mclk: Cell<Vec<DpmLevel>>,
+    pp_dpm_sclk: Cell<Vec<DpmLevel>>,
+    pp_od_clk_voltage: Cell<String>,
+    pp_power_profile_mode: Cell<Vec<PowerProfile>>,
+}
+
+impl Cache {
+    pub const AUTO: &'static str = crate::amdgpu::AUTO;
+    pub const LOW: &'static str = crate::amdgpu::LOW;
+    pub const HIGH: &'static str = crate::amdgpu::HIGH;
+    pub const MANUAL: &'static str = crate::amdgpu::MANUAL;
+    pub const PROFILE_STANDARD: &'static str = crate::amdgpu::PROFILE_STANDARD;
+    pub const PROFILE_MIN_SCLK: &'static str = crate::amdgpu::PROFILE_MIN_SCLK;
+    pub const PROFILE_MIN_MCLK: &'static str = crate::amdgpu::PROFILE_MIN_MCLK;
+    pub const PROFILE_PEAK: &'static str = crate::amdgpu::PROFILE_PEAK;
+    pub const PROFILE_EXIT: &'static str = crate::amdgpu::PROFILE_EXIT;
+
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        amdgpu::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        amdgpu::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        amdgpu::ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        amdgpu::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            gpu_busy_percent: Cell::default(),
+            mem_info_vis_vram_total: Cell::default(),
+            mem_info_vis_vram_used: Cell::default(),
+            mem_info_vram_total: Cell::default(),
+            mem_info_vram_used: Cell::default(),
+            power_dpm_force_performance_level: Cell::default(),
+            power1_cap: Cell::default(),
+            power1_cap_max: Cell::default(),
+            pp_dpm_mclk: Cell::default(),
+            pp_dpm_sclk: Cell::default(),
+            pp_od_clk_voltage: Cell::default(),
+            pp_power_profile_mode: Cell::default(),
+        }
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(
+            self.gpu_busy_percent.clear(),
+            self.mem_info_vis_vram_total.clear(),
+            self.mem_info_vis_vram_used.clear(),
+            self.mem_info_vram_total.clear(),
+            self.mem_info_vram_used.clear(),
+            self.power_dpm_force_performance_level.clear(),
+            self.power1_cap.clear(),
+            self.power1_cap_max.clear(),
+            self.pp_dpm_mclk.clear(),
+            self.pp_dpm_sclk.clear(),
+            self.pp_od_clk_voltage.clear(),
+            self.pp_power_profile_mode.clear(),
+        );
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub async fn gpu_busy_percent(&self) -> Result<u64> {
+        self.gpu_busy_percent
+            .get_or_load(amdgpu::gpu_busy_percent(self.id))
+            .await
+    }
+
+    pub async fn mem_info_vis_vram_total(&self) -> Result<u64> {
+        self.mem_info_vis_vram_total
+            .get_or_load(amdgpu::mem_info_vis_vram_total(self.id))
+            .await
+    }
+
+    pub async fn mem_info_vis_vram_used(&self) -> Result<u64> {
+        self.mem_info_vis_vram_used
+            .get_or_load(amdgpu::mem_info_vis_vram_used(self.id))
+            .await
+    }
+
+    pub async fn mem_info_vram_total(&self) -> Result<u64> {
+        self.mem_info_vram_total
+            .get_or_load(amdgpu::mem_info_vram_total(self.id))
+            .await
+    }
+
+    pub async fn mem_info_vram_used(&self) -> Result<u64> {
+        self.mem_info_vram_used
+            .get_or_load(amdgpu::mem_info_vram_used(self.id))
+            .await
+    }
+
+    pub async fn power_dpm_force_performance_level(&self) -> Result<String> {
+        self.power_dpm_force_performance_level
+            .get_or_load(amdgpu::power_dpm_force_performance_level(self.id))
+            .await
+    }
+
+    pub async fn power1_cap(&self) -> Result<u64> {
+        self.power1_cap
+            .get_or_load(amdgpu::power1_cap(self.id))
+            .await
+    }
+
+    pub async fn power1_cap_max(&self) -> Result<u64> {
+        self.power1_cap_max
+            .get_or_load(amdgpu::power1_cap_max(self.id))
+            .await
+    }
+
+    pub async fn pp_dpm_mclk(&self) -> Result<Vec<DpmLevel>> {
+        self.pp_dpm_mclk
+            .get_or_load(amdgpu::pp_dpm_mclk(self.id))
+            .await
+    }
+
+    pub async fn pp_dpm_sclk(&self) -> Result<Vec<DpmLevel>> {
+        self.pp_dpm_sclk
+            .get_or_load(amdgpu::pp_dpm_sclk(self.id))
+            .await
+    }
+
+    pub async fn pp_od_clk_voltage(&self) -> Result<String> {
+        self.pp_od_clk_voltage
+            .get_or_load(amdgpu::pp_od_clk_voltage(self.id))
+            .await
+    }
+
+    pub async fn pp_power_profile_mode(&self) -> Result<Vec<PowerProfile>> {
+        self.pp_power_profile_mode
+            .get_or_load(amdgpu::pp_power_profile_mode(self.id))
+            .await
+    }
+
+    pub async fn set_power_dpm_force_performance_level(&self, v: impl AsRef<str>) -> Result<()> {
+        self.power_dpm_force_performance_level
+            .clear_if_ok(amdgpu::set_power_dpm_force_performance_level(
+                self.id,
+                v.as_ref(),
+            ))
+            .await
+    }
+
+    pub async fn set_power1_cap(&self, v: u64) -> Result<()> {
+        self.power1_cap
+            .clear_if_ok(amdgpu::set_power1_cap(self.id, v))
+            .await
+    }
+
+    pub async fn set_pp_dpm_mclk(&self, v: &[u64]) -> Result<()> {
+        self.pp_dpm_mclk
+            .clear_if_ok(amdgpu::set_pp_dpm_mclk(self.id, v))
+            .await
+    }
+
+    pub async fn set_pp_dpm_sclk(&self, v: &[u64]) -> Result<()> {
+        self.pp_dpm_sclk
+            .clear_if_ok(amdgpu::set_pp_dpm_sclk(self.id, v))
+            .await
+    }
+
+    pub async fn set_pp_od_clk_voltage(&self, v: impl AsRef<str>) -> Result<()> {
+        self.pp_od_clk_voltage
+            .clear_if_ok(amdgpu::set_pp_od_clk_voltage(self.id, v.as_ref()))
+            .await
+    }
+
+    pub async fn set_pp_power_profile_mode(&self, v: u64) -> Result<()> {
+        self.pp_power_profile_mode
+            .clear_if_ok(amdgpu::set_pp_power_profile_mode(self.id, v))
+            .await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(v: Values) -> Self {
+        Self::new(v.id())
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(v: &Values) -> Self {
+        Self::new(v.id())
+    }
+}
@@ -0,0 +1,381 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub(crate) mod path;
+mod values;
+
+use std::path::Path;
+
+use futures::pin_mut;
+use futures::stream::{Stream, TryStreamExt as _};
+
+#[cfg(feature = "cache")]
+pub use crate::amdgpu::cache::Cache;
+pub use crate::amdgpu::values::Values;
+use crate::util::sysfs;
+use crate::{drm, Error, Result};
+
+pub const AUTO: &str = "auto";
+pub const LOW: &str = "low";
+pub const HIGH: &str = "high";
+pub const MANUAL: &str = "manual";
+pub const PROFILE_STANDARD: &str = "profile_standard";
+pub const PROFILE_MIN_SCLK: &str = "profile_min_sclk";
+pub const PROFILE_MIN_MCLK: &str = "profile_min_mclk";
+pub const PROFILE_PEAK: &str = "profile_peak";
+pub const PROFILE_EXIT: &str = "profile_exit";
+
+// One line of `pp_dpm_sclk` or `pp_dpm_mclk`, e.g. `1: 800Mhz *`, where the
+// asterisk marks the active level. The deep sleep level that some asics list
+// as `S: 19Mhz` has no index, since it can't be selected with `set_pp_dpm_*`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DpmLevel {
+    pub index: Option<u64>,
+    pub freq_mhz: u64,
+    pub active: bool,
+}
+
+// One profile of `pp_power_profile_mode`, e.g. ` 1 3D_FULL_SCREEN *:`. The
+// per-clock tuning rows that follow each profile on newer asics are skipped.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PowerProfile {
+    pub index: u64,
+    pub name: String,
+    pub active: bool,
+}
+
+fn parse_dpm_levels(s: &str) -> Option<Vec<DpmLevel>> {
+    let mut r = vec![];
+    for line in s.lines().filter(|l| !l.trim().is_empty()) {
+        let (index, rest) = line.split_once(':')?;
+        let index = match index.trim() {
+            "S" => None,
+            v => Some(v.parse::<u64>().ok()?),
+        };
+        let rest = rest.trim();
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let freq_mhz = rest[..digits].parse::<u64>().ok()?;
+        let active = rest.ends_with('*');
+        r.push(DpmLevel {
+            index,
+            freq_mhz,
+            active,
+        });
+    }
+    Some(r)
+}
+
+fn parse_power_profiles(s: &str) -> Vec<PowerProfile> {
+    let mut r = vec![];
+    for line in s.lines() {
+        let (head, _) = match line.split_once(':') {
+            Some(v) => v,
+            None => continue,
+        };
+        let mut p = head.split_whitespace();
+        let index = match p.next().and_then(|v| v.parse::<u64>().ok()) {
+            Some(v) => v,
+            None => continue,
+        };
+        let name: String = p.collect::<Vec<_>>().join(" ");
+        let active = name.ends_with('*');
+        let name = name.trim_end_matches('*').trim().to_string();
+        if name.is_empty() {
+            continue;
+        }
+        r.push(PowerProfile {
+            index,
+            name,
+            active,
+        });
+    }
+    r
+}
+
+async fn read_dpm_levels(path: &Path) -> Result<Vec<DpmLevel>> {
+    let s = sysfs::read_string(path).await?;
+    parse_dpm_levels(&s).ok_or_else(|| Error::sysfs_parse(path, "DpmLevel", s))
+}
+
+// The kernel ignores level masks unless the performance level is `manual`.
+async fn write_dpm_levels(id: u64, path: &Path, levels: &[u64]) -> Result<()> {
+    if levels.is_empty() {
+        return Err(Error::non_sequitor("No dpm levels given"));
+    }
+    let level = power_dpm_force_performance_level(id).await?;
+    if level != MANUAL {
+        let s = format!(
+            "Dpm levels require performance level {}, found {}",
+            MANUAL, level
+        );
+        return Err(Error::non_sequitor(s));
+    }
+    let v: Vec<_> = levels.iter().map(|v| v.to_string()).collect();
+    sysfs::write_string(path, &v.join(" ")).await
+}
+
+async fn hwmon(id: u64) -> Result<u64> {
+    let s = sysfs::read_ids(&path::hwmon_root(id), "hwmon");
+    pin_mut!(s);
+    match s.try_next().await? {
+        Some(v) => Ok(v),
+        None => {
+            let s = format!("No hwmon device for drm card {}", id);
+            Err(Error::non_sequitor(s))
+        },
+    }
+}
+
+pub async fn available() -> Result<bool> {
+    Ok(path::module().is_dir())
+}
+
+pub async fn exists(id: u64) -> Result<bool> {
+    let r = if drm::exists(id).await? {
+        "amdgpu" == drm::driver(id).await?.as_str()
+    } else {
+        false
+    };
+    Ok(r)
+}
+
+pub fn ids() -> impl Stream<Item = Result<u64>> {
+    drm::ids_for_driver("amdgpu")
+}
+
+pub async fn gpu_busy_percent(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::gpu_busy_percent(id)).await
+}
+
+pub async fn mem_info_vis_vram_total(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::mem_info_vis_vram_total(id)).await
+}
+
+pub async fn mem_info_vis_vram_used(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::mem_info_vis_vram_used(id)).await
+}
+
+pub async fn mem_info_vram_total(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::mem_info_vram_total(id)).await
+}
+
+pub async fn mem_info_vram_used(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::mem_info_vram_used(id)).await
+}
+
+pub async fn power_dpm_force_performance_level(id: u64) -> Result<String> {
+    sysfs::read_string(&path::power_dpm_force_performance_level(id)).await
+}
+
+pub async fn power1_cap(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::power1_cap(id, hwmon(id).await?)).await
+}
+
+pub async fn power1_cap_max(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::power1_cap_max(id, hwmon(id).await?)).await
+}
+
+pub async fn pp_dpm_mclk(id: u64) -> Result<Vec<DpmLevel>> {
+    read_dpm_levels(&path::pp_dpm_mclk(id)).await
+}
+
+pub async fn pp_dpm_sclk(id: u64) -> Result<Vec<DpmLevel>> {
+    read_dpm_levels(&path::pp_dpm_sclk(id)).await
+}
+
+pub async fn pp_od_clk_voltage(id: u64) -> Result<String> {
+    sysfs::read_string(&path::pp_od_clk_voltage(id)).await
+}
+
+pub async fn pp_power_profile_mode(id: u64) -> Result<Vec<PowerProfile>> {
+    let s = sysfs::read_string(&path::pp_power_profile_mode(id)).await?;
+    Ok(parse_power_profiles(&s))
+}
+
+pub async fn set_power_dpm_force_performance_level(id: u64, v: &str) -> Result<()> {
+    sysfs::write_string(&path::power_dpm_force_performance_level(id), v).await
+}
+
+pub async fn set_power1_cap(id: u64, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::power1_cap(id, hwmon(id).await?), v).await
+}
+
+pub async fn set_pp_dpm_mclk(id: u64, levels: &[u64]) -> Result<()> {
+    write_dpm_levels(id, &path::pp_dpm_mclk(id), levels).await
+}
+
+pub async fn set_pp_dpm_sclk(id: u64, levels: &[u64]) -> Result<()> {
+    write_dpm_levels(id, &path::pp_dpm_sclk(id), levels).await
+}
+
+// Takes a single command, e.g. `s 1 1800`; edits are applied with `c` and
+// discarded with `r`.
+pub async fn set_pp_od_clk_voltage(id: u64, v: &str) -> Result<()> {
+    sysfs::write_string(&path::pp_od_clk_voltage(id), v).await
+}
+
+pub async fn set_pp_power_profile_mode(id: u64, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::pp_power_profile_mode(id), v).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(index: u64, freq_mhz: u64, active: bool) -> DpmLevel {
+        DpmLevel {
+            index: Some(index),
+            freq_mhz,
+            active,
+        }
+    }
+
+    fn profile(index: u64, name: &str, active: bool) -> PowerProfile {
+        PowerProfile {
+            index,
+            name: name.to_string(),
+            active,
+        }
+    }
+
+    #[test]
+    fn dpm_levels_polaris() {
+        let s = "0: 300Mhz \n1: 600Mhz \n2: 900Mhz \n3: 1145Mhz \n4: 1215Mhz \n5: 1257Mhz \n\
+                 6: 1300Mhz \n7: 1366Mhz *\n";
+        let r = parse_dpm_levels(s).unwrap();
+        assert_eq!(r.len(), 8);
+        assert_eq!(r[0], level(0, 300, false));
+        assert_eq!(r[7], level(7, 1366, true));
+        assert_eq!(r.iter().filter(|v| v.active).count(), 1);
+    }
+
+    #[test]
+    fn dpm_levels_navi() {
+        let s = "0: 100Mhz \n1: 500Mhz \n2: 625Mhz \n3: 875Mhz *\n";
+        assert_eq!(
+            parse_dpm_levels(s).unwrap(),
+            vec![
+                level(0, 100, false),
+                level(1, 500, false),
+                level(2, 625, false),
+                level(3, 875, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn dpm_levels_deep_sleep() {
+        let s = "S: 19Mhz *\n0: 500Mhz \n1: 2482Mhz \n";
+        let sleep = DpmLevel {
+            index: None,
+            freq_mhz: 19,
+            active: true,
+        };
+        assert_eq!(
+            parse_dpm_levels(s).unwrap(),
+            vec![sleep, level(0, 500, false), level(1, 2482, false)]
+        );
+    }
+
+    #[test]
+    fn dpm_levels_smu13() {
+        let s = "0: 500Mhz \n1: 1239Mhz *\n2: 2482Mhz \n";
+        assert_eq!(
+            parse_dpm_levels(s).unwrap(),
+            vec![
+                level(0, 500, false),
+                level(1, 1239, true),
+                level(2, 2482, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn dpm_levels_invalid() {
+        assert_eq!(parse_dpm_levels("0: Mhz\n"), None);
+        assert_eq!(parse_dpm_levels("0 300Mhz\n"), None);
+        assert_eq!(parse_dpm_levels("X: 300Mhz\n"), None);
+        assert_eq!(parse_dpm_levels(""), Some(vec![]));
+    }
+
+    #[test]
+    fn power_profiles_polaris() {
+        let s = "\
+NUM        MODE_NAME     SCLK_UP_HYST   SCLK_DOWN_HYST SCLK_ACTIVE_LEVEL     MCLK_UP_HYST   MCLK_DOWN_HYST MCLK_ACTIVE_LEVEL
+  0   BOOTUP_DEFAULT:        -              -              -              -              -              -
+  1 3D_FULL_SCREEN *:        0            100             30              0            100             10
+  2   POWER_SAVING:       10              0             30              -              -              -
+  3          VIDEO:        -              -              -             10             16             31
+  4             VR:        0             11             50              0            100             10
+  5        COMPUTE:        0              5             30              -              -              -
+  6         CUSTOM:        -              -              -              -              -              -
+";
+        assert_eq!(
+            parse_power_profiles(s),
+            vec![
+                profile(0, "BOOTUP_DEFAULT", false),
+                profile(1, "3D_FULL_SCREEN", true),
+                profile(2, "POWER_SAVING", false),
+                profile(3, "VIDEO", false),
+                profile(4, "VR", false),
+                profile(5, "COMPUTE", false),
+                profile(6, "CUSTOM", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn power_profiles_navi() {
+        let s = "\
+NUM        MODE_NAME     CLOCK_TYPE(NAME) FPS MinFreqType MinActiveFreqType MinActiveFreq BoosterFreqType BoosterFreq PD_Data_limit_c PD_Data_error_coeff PD_Data_error_rate_coeff
+ 0 BOOTUP_DEFAULT*:
+                        0(       GFXCLK)       0       5       1       0       4     800 4587520  -65536       0
+                        1(       SOCCLK)       0       5       1       0       1       0 3276800  -65536   -6553
+                        2(        MEMLK)       0       5       1       0       4     800  327680  -65536       0
+ 1 3D_FULL_SCREEN :
+                        0(       GFXCLK)       0       5       1       0       4     650  629146   -6554       0
+                        1(       SOCCLK)       0       5       1       0       1       0 3276800  -65536   -6553
+                        2(        MEMLK)       0       5       1       0       4     800  327680  -65536     -65
+ 2   POWER_SAVING :
+                        0(       GFXCLK)       0       5       1       0       3       0  655360  -65536       0
+ 5        COMPUTE :
+                        0(       GFXCLK)       0       5       1       0       4     800  629146   -6554       0
+";
+        assert_eq!(
+            parse_power_profiles(s),
+            vec![
+                profile(0, "BOOTUP_DEFAULT", true),
+                profile(1, "3D_FULL_SCREEN", false),
+                profile(2, "POWER_SAVING", false),
+                profile(5, "COMPUTE", false),
+            ]
+        );
+    }
+
+    #[test]
+    fn power_profiles_smu13() {
+        let s = "\
+PROFILE_INDEX(NAME) CLOCK_TYPE(NAME) FPS MinActiveFreqType MinActiveFreq BoosterFreqType BoosterFreq PD_Data_limit_c PD_Data_error_coeff PD_Data_error_rate_coeff
+ 0 BOOTUP_DEFAULT :
+                        0(       GFXCLK)       0       1       0       0       0       0       0       0
+                        1(         FCLK)       0       1       0       0       0       0       0       0
+ 1 3D_FULL_SCREEN*:
+                        0(       GFXCLK)       1       1    1500       1    2500 3932160  -32768   -6553
+                        1(         FCLK)       1       1     800       1    1800 3932160  -32768   -6553
+ 6         CUSTOM :
+                        0(       GFXCLK)       0       1       0       0       0       0       0       0
+ 7      WINDOW_3D :
+                        0(       GFXCLK)       0       1       0       0       0       0       0       0
+";
+        assert_eq!(
+            parse_power_profiles(s),
+            vec![
+                profile(0, "BOOTUP_DEFAULT", false),
+                profile(1, "3D_FULL_SCREEN", true),
+                profile(6, "CUSTOM", false),
+                profile(7, "WINDOW_3D", false),
+            ]
+        );
+    }
+}
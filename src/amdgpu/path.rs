@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use crate::drm::path::device_attr;
+
+pub(crate) fn module() -> PathBuf {
+    PathBuf::from("/sys/module/amdgpu")
+}
+
+pub(crate) fn gpu_busy_percent(id: u64) -> PathBuf {
+    device_attr(id, "gpu_busy_percent")
+}
+
+pub(crate) fn mem_info_vis_vram_total(id: u64) -> PathBuf {
+    device_attr(id, "mem_info_vis_vram_total")
+}
+
+pub(crate) fn mem_info_vis_vram_used(id: u64) -> PathBuf {
+    device_attr(id, "mem_info_vis_vram_used")
+}
+
+pub(crate) fn mem_info_vram_total(id: u64) -> PathBuf {
+    device_attr(id, "mem_info_vram_total")
+}
+
+pub(crate) fn mem_info_vram_used(id: u64) -> PathBuf {
+    device_attr(id, "mem_info_vram_used")
+}
+
+pub(crate) fn power_dpm_force_performance_level(id: u64) -> PathBuf {
+    device_attr(id, "power_dpm_force_performance_level")
+}
+
+pub(crate) fn pp_dpm_mclk(id: u64) -> PathBuf {
+    device_attr(id, "pp_dpm_mclk")
+}
+
+pub(crate) fn pp_dpm_sclk(id: u64) -> PathBuf {
+    device_attr(id, "pp_dpm_sclk")
+}
+
+pub(crate) fn pp_od_clk_voltage(id: u64) -> PathBuf {
+    device_attr(id, "pp_od_clk_voltage")
+}
+
+pub(crate) fn pp_power_profile_mode(id: u64) -> PathBuf {
+    device_attr(id, "pp_power_profile_mode")
+}
+
+pub(crate) fn hwmon_root(id: u64) -> PathBuf {
+    device_attr(id, "hwmon")
+}
+
+pub(crate) fn hwmon_attr(id: u64, hwmon: u64, a: &str) -> PathBuf {
+    hwmon_root(id).join(format!("hwmon{}", hwmon)).join(a)
+}
+
+pub(crate) fn power1_cap(id: u64, hwmon: u64) -> PathBuf {
+    hwmon_attr(id, hwmon, "power1_cap")
+}
+
+pub(crate) fn power1_cap_max(id: u64, hwmon: u64) -> PathBuf {
+    hwmon_attr(id, hwmon, "power1_cap_max")
+}
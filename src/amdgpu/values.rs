@@ -0,0 +1,134 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::amdgpu::{self, DpmLevel, PowerProfile};
+#[cfg(feature = "cache")]
+use crate::amdgpu::Cache;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values {
+    id: u64,
+}
+
+impl Values {
+    pub const AUTO: &'static str = crate::amdgpu::AUTO;
+    pub const LOW: &'static str = crate::amdgpu::LOW;
+    pub const HIGH: &'static str = crate::amdgpu::HIGH;
+    pub const MANUAL: &'static str = crate::amdgpu::MANUAL;
+    pub const PROFILE_STANDARD: &'static str = crate::amdgpu::PROFILE_STANDARD;
+    pub const PROFILE_MIN_SCLK: &'static str = crate::amdgpu::PROFILE_MIN_SCLK;
+    pub const PROFILE_MIN_MCLK: &'static str = crate::amdgpu::PROFILE_MIN_MCLK;
+    pub const PROFILE_PEAK: &'static str = crate::amdgpu::PROFILE_PEAK;
+    pub const PROFILE_EXIT: &'static str = crate::amdgpu::PROFILE_EXIT;
+
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        amdgpu::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        amdgpu::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        amdgpu::ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        amdgpu::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn gpu_busy_percent(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::gpu_busy_percent(self.id)
+    }
+
+    pub fn mem_info_vis_vram_total(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::mem_info_vis_vram_total(self.id)
+    }
+
+    pub fn mem_info_vis_vram_used(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::mem_info_vis_vram_used(self.id)
+    }
+
+    pub fn mem_info_vram_total(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::mem_info_vram_total(self.id)
+    }
+
+    pub fn mem_info_vram_used(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::mem_info_vram_used(self.id)
+    }
+
+    pub fn power_dpm_force_performance_level(&self) -> impl Future<Output = Result<String>> {
+        amdgpu::power_dpm_force_performance_level(self.id)
+    }
+
+    pub fn power1_cap(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::power1_cap(self.id)
+    }
+
+    pub fn power1_cap_max(&self) -> impl Future<Output = Result<u64>> {
+        amdgpu::power1_cap_max(self.id)
+    }
+
+    pub fn pp_dpm_mclk(&self) -> impl Future<Output = Result<Vec<DpmLevel>>> {
+        amdgpu::pp_dpm_mclk(self.id)
+    }
+
+    pub fn pp_dpm_sclk(&self) -> impl Future<Output = Result<Vec<DpmLevel>>> {
+        amdgpu::pp_dpm_sclk(self.id)
+    }
+
+    pub fn pp_od_clk_voltage(&self) -> impl Future<Output = Result<String>> {
+        amdgpu::pp_od_clk_voltage(self.id)
+    }
+
+    pub fn pp_power_profile_mode(&self) -> impl Future<Output = Result<Vec<PowerProfile>>> {
+        amdgpu::pp_power_profile_mode(self.id)
+    }
+
+    pub async fn set_power_dpm_force_performance_level(&self, v: impl AsRef<str>) -> Result<()> {
+        amdgpu::set_power_dpm_force_performance_level(self.id, v.as_ref()).await
+    }
+
+    pub fn set_power1_cap(&self, v: u64) -> impl Future<Output = Result<()>> {
+        amdgpu::set_power1_cap(self.id, v)
+    }
+
+    pub async fn set_pp_dpm_mclk(&self, v: &[u64]) -> Result<()> {
+        amdgpu::set_pp_dpm_mclk(self.id, v).await
+    }
+
+    pub async fn set_pp_dpm_sclk(&self, v: &[u64]) -> Result<()> {
+        amdgpu::set_pp_dpm_sclk(self.id, v).await
+    }
+
+    pub async fn set_pp_od_clk_voltage(&self, v: impl AsRef<str>) -> Result<()> {
+        amdgpu::set_pp_od_clk_voltage(self.id, v.as_ref()).await
+    }
+
+    pub fn set_pp_power_profile_mode(&self, v: u64) -> impl Future<Output = Result<()>> {
+        amdgpu::set_pp_power_profile_mode(self.id, v)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<Cache> for Values {
+    fn from(v: Cache) -> Self {
+        Self::new(v.id())
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<&Cache> for Values {
+    fn from(v: &Cache) -> Self {
+        Self::new(v.id())
+    }
+}
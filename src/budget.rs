@@ -0,0 +1,285 @@
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::stream::Stream;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::control::{Controller, Setting};
+use crate::Result;
+
+const UJ_PER_WH: f64 = 3_600_000_000.;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Phase {
+    Relaxed,
+    Throttled,
+    Exhausted,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct State {
+    pub phase: Phase,
+    pub budget_uj: u64,
+    pub window: Duration,
+    pub windows: u64,
+    pub elapsed: Duration,
+    pub consumed_uj: u64,
+    pub remaining_uj: u64,
+    pub power_uw: u64,
+    pub target_uw: u64,
+    pub settings: Vec<Setting>,
+}
+
+impl State {
+    pub fn consumed_pct(&self) -> f64 {
+        if self.budget_uj > 0 {
+            self.consumed_uj as f64 * 100. / self.budget_uj as f64
+        } else {
+            100.
+        }
+    }
+}
+
+// Each step sets the controller target to the power that would spend exactly
+// the remaining budget over the rest of the window, so limits tighten as the
+// budget is consumed faster than the window elapses. Limits in effect when
+// the controller was created are restored whenever a new window starts.
+#[derive(Clone, Debug)]
+pub struct Budget {
+    controller: Controller,
+    relaxed: Vec<Setting>,
+    start: Instant,
+    state: State,
+}
+
+fn target_uw(s: &State) -> u64 {
+    let left = s.window.saturating_sub(s.elapsed).as_micros();
+    (s.remaining_uj as u128 * 1_000_000)
+        .checked_div(left)
+        .map_or(u64::MAX, |v| v.min(u64::MAX as u128) as u64)
+}
+
+// The share of `energy_uj`, spent evenly over `total`, that falls in `part`.
+fn share(energy_uj: u64, part: Duration, total: Duration) -> u64 {
+    (energy_uj as u128 * part.as_micros())
+        .checked_div(total.as_micros())
+        .map_or(energy_uj, |v| v.min(energy_uj as u128) as u64)
+}
+
+// Splits `energy_uj`, spent evenly from `from` to `now`, at each window end
+// after `start`. The last part belongs to the window that `now` falls in.
+fn split(
+    energy_uj: u64,
+    from: Instant,
+    now: Instant,
+    start: Instant,
+    window: Duration,
+) -> Vec<u64> {
+    let elapsed = now.saturating_duration_since(from);
+    let (mut from, mut start, mut left_uj) = (from, start, energy_uj);
+    let mut r = vec![];
+    while !window.is_zero() && now >= start + window {
+        let end = start + window;
+        let v = share(energy_uj, end.saturating_duration_since(from), elapsed).min(left_uj);
+        r.push(v);
+        left_uj -= v;
+        from = from.max(end);
+        start = end;
+    }
+    r.push(left_uj);
+    r
+}
+
+impl Budget {
+    // Members count as throttled while below the levels the controller has
+    // here, which are also the levels `restore` returns a fresh controller to.
+    pub fn new(budget_uj: u64, window: Duration, mut controller: Controller) -> Self {
+        let relaxed = controller.settings();
+        let mut state = State {
+            phase: Phase::Relaxed,
+            budget_uj,
+            window,
+            windows: 0,
+            elapsed: Duration::ZERO,
+            consumed_uj: 0,
+            remaining_uj: budget_uj,
+            power_uw: 0,
+            target_uw: 0,
+            settings: relaxed.clone(),
+        };
+        state.target_uw = target_uw(&state);
+        controller.set_target_uw(state.target_uw);
+        Self {
+            controller,
+            relaxed,
+            start: Instant::now(),
+            state,
+        }
+    }
+
+    pub fn from_wh(wh: f64, window: Duration, controller: Controller) -> Self {
+        Self::new((wh * UJ_PER_WH) as u64, window, controller)
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn controller(&self) -> &Controller {
+        &self.controller
+    }
+
+    pub fn set_budget_uj(&mut self, v: u64) {
+        let s = &mut self.state;
+        s.budget_uj = v;
+        s.remaining_uj = s.budget_uj.saturating_sub(s.consumed_uj);
+        s.target_uw = target_uw(s);
+        self.controller.set_target_uw(s.target_uw);
+    }
+
+    pub async fn restore(&mut self) -> Result<()> {
+        self.controller.restore().await
+    }
+
+    async fn reset(&mut self) -> Result<()> {
+        self.controller.restore().await?;
+        self.start += self.state.window;
+        let s = &mut self.state;
+        s.windows += 1;
+        s.consumed_uj = 0;
+        s.remaining_uj = s.budget_uj;
+        Ok(())
+    }
+
+    // The energy of a step that crosses into a new window is split between
+    // the windows by time.
+    pub async fn step(&mut self) -> Result<&State> {
+        let step = self.controller.step().await?;
+        let now = Instant::now();
+        let energy_uj = (step.power_uw as u128 * step.elapsed.as_micros() / 1_000_000) as u64;
+        let from = now.checked_sub(step.elapsed).unwrap_or(self.start);
+        let mut parts = split(energy_uj, from, now, self.start, self.state.window);
+        let left_uj = parts.pop().unwrap_or_default();
+        for v in parts {
+            self.state.consumed_uj = self.state.consumed_uj.saturating_add(v);
+            self.reset().await?;
+        }
+        let s = &mut self.state;
+        s.consumed_uj = s.consumed_uj.saturating_add(left_uj);
+        s.remaining_uj = s.budget_uj.saturating_sub(s.consumed_uj);
+        s.elapsed = now - self.start;
+        s.power_uw = step.power_uw;
+        s.target_uw = target_uw(s);
+        s.settings = self.controller.settings();
+        s.phase = if s.remaining_uj == 0 {
+            Phase::Exhausted
+        } else if s
+            .settings
+            .iter()
+            .zip(&self.relaxed)
+            .any(|(v, r)| v.level < r.level - 1e-9)
+        {
+            Phase::Throttled
+        } else {
+            Phase::Relaxed
+        };
+        self.controller.set_target_uw(s.target_uw);
+        Ok(&self.state)
+    }
+
+    // As with `Controller::run`, limits are restored when a step fails but
+    // not when the stream is dropped.
+    pub fn run(&mut self, interval: Duration) -> impl Stream<Item = Result<State>> + '_ {
+        try_stream! {
+            let mut interval = tokio::time::interval(interval);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let r = self.step().await.cloned();
+                if r.is_err() {
+                    self.restore().await?;
+                }
+                yield r?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEC: Duration = Duration::from_secs(1);
+
+    fn state(remaining_uj: u64, window: Duration, elapsed: Duration) -> State {
+        State {
+            phase: Phase::Relaxed,
+            budget_uj: remaining_uj,
+            window,
+            windows: 0,
+            elapsed,
+            consumed_uj: 0,
+            remaining_uj,
+            power_uw: 0,
+            target_uw: 0,
+            settings: vec![],
+        }
+    }
+
+    #[test]
+    fn target() {
+        // 3.6 J over the remaining 30 s.
+        assert_eq!(target_uw(&state(3_600_000, 60 * SEC, 30 * SEC)), 120_000);
+        assert_eq!(target_uw(&state(0, 60 * SEC, 30 * SEC)), 0);
+        // No time left in the window.
+        assert_eq!(target_uw(&state(1, 60 * SEC, 60 * SEC)), u64::MAX);
+        assert_eq!(
+            target_uw(&state(
+                u64::MAX,
+                60 * SEC,
+                60 * SEC - Duration::from_micros(1)
+            )),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn share_by_time() {
+        assert_eq!(share(1000, SEC, 4 * SEC), 250);
+        assert_eq!(share(1000, Duration::ZERO, 4 * SEC), 0);
+        assert_eq!(share(1000, 8 * SEC, 4 * SEC), 1000);
+        assert_eq!(share(1000, SEC, Duration::ZERO), 1000);
+    }
+
+    #[test]
+    fn split_within_window() {
+        let start = Instant::now();
+        let r = split(1000, start + SEC, start + 3 * SEC, start, 10 * SEC);
+        assert_eq!(r, vec![1000]);
+    }
+
+    #[test]
+    fn split_across_windows() {
+        let start = Instant::now();
+        // 8 s to 12 s with a 10 s window: half in each.
+        let r = split(1000, start + 8 * SEC, start + 12 * SEC, start, 10 * SEC);
+        assert_eq!(r, vec![500, 500]);
+        // 5 s to 25 s spans the end of one window, a whole window and half
+        // of the next.
+        let r = split(2000, start + 5 * SEC, start + 25 * SEC, start, 10 * SEC);
+        assert_eq!(r, vec![500, 1000, 500]);
+        // Ending exactly on a window end starts the next window empty.
+        let r = split(1000, start + 5 * SEC, start + 10 * SEC, start, 10 * SEC);
+        assert_eq!(r, vec![1000, 0]);
+    }
+
+    #[test]
+    fn split_zero_window() {
+        let start = Instant::now();
+        assert_eq!(
+            split(1000, start, start + SEC, start, Duration::ZERO),
+            vec![1000]
+        );
+    }
+}
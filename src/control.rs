@@ -0,0 +1,472 @@
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::future::try_join_all;
+use futures::stream::{Stream, TryStreamExt as _};
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::intel_rapl::zone::meter::{self, Counter};
+#[cfg(feature = "nvml")]
+use crate::nvml;
+use crate::powercap::{constraint, zone};
+use crate::{i915, intel_rapl, Error, Result};
+
+// Rapl limits are in microwatts, nvml limits in milliwatts, and i915 ceilings
+// in MHz. Rapl constraints are named by their powercap id, so both
+// `intel-rapl` and `intel-rapl-mmio` limits can be controlled.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Device {
+    Rapl(constraint::Id),
+    #[cfg(feature = "nvml")]
+    Nvml(u64),
+    I915(u64),
+}
+
+// `max_power_uw` is missing or reads zero for some constraints, so fall back
+// to the zone's first constraint and then to the zone's own range.
+async fn rapl_max_uw(id: &constraint::Id) -> Result<u64> {
+    let first = constraint::Id::new(id.zone().clone(), 0);
+    if let Ok(v @ 1..) = constraint::max_power_uw(id.clone()).await {
+        return Ok(v);
+    }
+    if let Ok(v @ 1..) = constraint::max_power_uw(first).await {
+        return Ok(v);
+    }
+    if let Ok(v @ 1..) = zone::max_power_range_uw(id.zone().clone()).await {
+        return Ok(v);
+    }
+    let s = format!("No maximum power limit for rapl constraint {:?}", id);
+    Err(Error::non_sequitor(s))
+}
+
+// intel_rapl never creates `min_power_uw`, so unless `Member::min` is set a
+// rapl constraint is not throttled below this fraction of its maximum, so an
+// unknown floor can't starve the zone.
+const RAPL_MIN_DIVISOR: u64 = 4;
+
+impl Device {
+    async fn range(&self) -> Result<(u64, u64, u64)> {
+        let r = match *self {
+            Self::Rapl(ref id) => {
+                let (max, v) =
+                    tokio::try_join!(rapl_max_uw(id), constraint::power_limit_uw(id.clone()),)?;
+                let min = match constraint::min_power_uw(id.clone()).await {
+                    Ok(min) if min > 0 => min,
+                    _ => max / RAPL_MIN_DIVISOR,
+                };
+                (min, max, v)
+            },
+            #[cfg(feature = "nvml")]
+            Self::Nvml(id) => {
+                let (min, max, v) = tokio::try_join!(
+                    nvml::power_min_limit(id),
+                    nvml::power_max_limit(id),
+                    nvml::power_limit(id),
+                )?;
+                (min as u64, max as u64, v as u64)
+            },
+            Self::I915(id) => tokio::try_join!(
+                i915::rpn_freq_mhz(id),
+                i915::rp0_freq_mhz(id),
+                i915::max_freq_mhz(id),
+            )?,
+        };
+        Ok(r)
+    }
+
+    async fn set(&self, v: u64) -> Result<()> {
+        match *self {
+            Self::Rapl(ref id) => constraint::set_power_limit_uw(id.clone(), v).await,
+            #[cfg(feature = "nvml")]
+            Self::Nvml(id) => nvml::set_power_limit(id, v as u32).await,
+            Self::I915(id) => i915::set_max_freq_mhz(id, v).await,
+        }
+    }
+}
+
+// When throttling, devices with the lowest priority are adjusted first and
+// higher priorities only once those are at their minimum; relaxing works in
+// the opposite order. Within a priority the adjustment is split by weight.
+// `min` and `max` override the device's own range; rapl constraints without
+// `min_power_uw` default to a quarter of their maximum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Member {
+    pub device: Device,
+    pub priority: u32,
+    pub weight: f64,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+}
+
+impl Member {
+    pub fn new(device: Device) -> Self {
+        Self {
+            device,
+            priority: 0,
+            weight: 1.,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl From<Device> for Member {
+    fn from(v: Device) -> Self {
+        Self::new(v)
+    }
+}
+
+// `level` is the member's position between its minimum and maximum, from 0
+// to 1, and `value` is the limit written for it in the device's own unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Setting {
+    pub device: Device,
+    pub min: u64,
+    pub max: u64,
+    pub level: f64,
+    pub value: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Step {
+    pub elapsed: Duration,
+    pub power_uw: u64,
+    pub target_uw: u64,
+    pub settings: Vec<Setting>,
+}
+
+#[derive(Clone, Debug)]
+struct Actuator {
+    member: Member,
+    initial: u64,
+    setting: Setting,
+}
+
+fn level(min: u64, max: u64, value: u64) -> f64 {
+    if max > min {
+        (value.clamp(min, max) - min) as f64 / (max - min) as f64
+    } else {
+        1.
+    }
+}
+
+impl Actuator {
+    async fn new(member: Member) -> Result<Self> {
+        let (min, max, v) = member.device.range().await?;
+        let min = member.min.unwrap_or(min);
+        let max = member.max.unwrap_or(max).max(min);
+        let value = v.clamp(min, max);
+        let setting = Setting {
+            device: member.device.clone(),
+            min,
+            max,
+            level: level(min, max, value),
+            value,
+        };
+        Ok(Self {
+            member,
+            initial: v,
+            setting,
+        })
+    }
+
+    async fn apply(&mut self) -> Result<()> {
+        let s = &mut self.setting;
+        let v = s.min + ((s.max - s.min) as f64 * s.level).round() as u64;
+        if v != s.value {
+            s.device.set(v).await?;
+            s.value = v;
+        }
+        Ok(())
+    }
+}
+
+// Spreads `step`, in level units, across actuators by priority and weight.
+// Whatever a priority group can't absorb carries over to the next group.
+fn distribute(actuators: &mut [Actuator], mut step: f64) {
+    let mut priorities: Vec<_> = actuators.iter().map(|a| a.member.priority).collect();
+    priorities.sort_unstable();
+    priorities.dedup();
+    if step > 0. {
+        priorities.reverse();
+    }
+    for p in priorities {
+        loop {
+            let active: Vec<_> = actuators
+                .iter_mut()
+                .filter(|a| a.member.priority == p && a.member.weight > 0.)
+                .filter(|a| {
+                    (step > 0. && a.setting.level < 1.) || (step < 0. && a.setting.level > 0.)
+                })
+                .collect();
+            let weight: f64 = active.iter().map(|a| a.member.weight).sum();
+            if active.is_empty() || step.abs() < 1e-9 {
+                break;
+            }
+            let mut used = 0.;
+            for a in active {
+                let want = step * a.member.weight / weight;
+                let level = (a.setting.level + want).clamp(0., 1.);
+                used += level - a.setting.level;
+                a.setting.level = level;
+            }
+            step -= used;
+            if used.abs() < 1e-12 {
+                break;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Controller {
+    target_uw: u64,
+    gain: f64,
+    last: Instant,
+    zones: Vec<Counter>,
+    #[cfg(feature = "nvml")]
+    gpus: Vec<u64>,
+    actuators: Vec<Actuator>,
+}
+
+impl Controller {
+    pub const DEFAULT_GAIN: f64 = 0.5;
+
+    // Measured power is the sum of the rapl zones and nvml gpus under control.
+    // When no rapl constraint is a member, all rapl package zones are measured
+    // instead, which covers members such as i915 that have no meter of their
+    // own.
+    pub async fn new<I, M>(target_uw: u64, members: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = M>,
+        M: Into<Member>,
+    {
+        let members: Vec<Member> = members.into_iter().map(Into::into).collect();
+        if members.is_empty() {
+            return Err(Error::non_sequitor("Power controller has no members"));
+        }
+        let mut zones: BTreeSet<_> = members
+            .iter()
+            .filter_map(|m| match &m.device {
+                Device::Rapl(id) => Some(id.zone().clone()),
+                _ => None,
+            })
+            .collect();
+        #[cfg(feature = "nvml")]
+        let gpus: Vec<_> = members
+            .iter()
+            .filter_map(|m| match m.device {
+                Device::Nvml(id) => Some(id),
+                _ => None,
+            })
+            .collect();
+        if zones.is_empty() && intel_rapl::available().await? {
+            zones = meter::package_ids().try_collect().await?;
+        }
+        #[cfg(feature = "nvml")]
+        let unmetered = zones.is_empty() && gpus.is_empty();
+        #[cfg(not(feature = "nvml"))]
+        let unmetered = zones.is_empty();
+        if unmetered {
+            return Err(Error::non_sequitor(
+                "Power controller has no power to measure",
+            ));
+        }
+        let (zones, actuators) = tokio::try_join!(
+            try_join_all(zones.into_iter().map(Counter::new)),
+            try_join_all(members.into_iter().map(Actuator::new)),
+        )?;
+        Ok(Self {
+            target_uw,
+            gain: Self::DEFAULT_GAIN,
+            last: Instant::now(),
+            zones,
+            #[cfg(feature = "nvml")]
+            gpus,
+            actuators,
+        })
+    }
+
+    pub fn target_uw(&self) -> u64 {
+        self.target_uw
+    }
+
+    pub fn set_target_uw(&mut self, v: u64) {
+        self.target_uw = v;
+    }
+
+    pub fn gain(&self) -> f64 {
+        self.gain
+    }
+
+    pub fn set_gain(&mut self, v: f64) {
+        self.gain = v;
+    }
+
+    pub fn settings(&self) -> Vec<Setting> {
+        self.actuators.iter().map(|a| a.setting.clone()).collect()
+    }
+
+    async fn power_uw(&mut self) -> Result<(u64, Duration)> {
+        let zones = try_join_all(self.zones.iter_mut().map(|c| c.update())).await?;
+        let now = Instant::now();
+        let elapsed = now - self.last;
+        self.last = now;
+        let r: u64 = zones
+            .into_iter()
+            .map(|(uj, e)| {
+                (uj as u128 * 1_000_000)
+                    .checked_div(e.as_micros())
+                    .unwrap_or_default() as u64
+            })
+            .sum();
+        #[cfg(feature = "nvml")]
+        let r = try_join_all(self.gpus.iter().map(|id| nvml::power(*id)))
+            .await?
+            .into_iter()
+            .fold(r, |r, v| r + v as u64 * 1_000);
+        Ok((r, elapsed))
+    }
+
+    // Measures power since the previous step and moves every member's level
+    // by `gain` times the relative error from the target.
+    pub async fn step(&mut self) -> Result<Step> {
+        let (power_uw, elapsed) = self.power_uw().await?;
+        // A zero target throttles every member toward its minimum.
+        let error = if self.target_uw > 0 {
+            (self.target_uw as f64 - power_uw as f64) / self.target_uw as f64
+        } else {
+            -1.
+        };
+        distribute(&mut self.actuators, self.gain * error);
+        try_join_all(self.actuators.iter_mut().map(|a| a.apply())).await?;
+        Ok(Step {
+            elapsed,
+            power_uw,
+            target_uw: self.target_uw,
+            settings: self.settings(),
+        })
+    }
+
+    // Writes back the limits that were in effect when the controller was
+    // created.
+    pub async fn restore(&mut self) -> Result<()> {
+        for a in &mut self.actuators {
+            let s = &mut a.setting;
+            s.device.set(a.initial).await?;
+            s.value = a.initial;
+            s.level = level(s.min, s.max, a.initial);
+        }
+        Ok(())
+    }
+
+    // Limits are restored when a step fails. Dropping the stream leaves the
+    // last limits in effect, so call `restore` once done with it.
+    pub fn run(&mut self, interval: Duration) -> impl Stream<Item = Result<Step>> + '_ {
+        try_stream! {
+            let mut interval = tokio::time::interval(interval);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let r = self.step().await;
+                if r.is_err() {
+                    self.restore().await?;
+                }
+                yield r?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn actuator(id: u64, priority: u32, weight: f64, level: f64) -> Actuator {
+        let device = Device::I915(id);
+        Actuator {
+            member: Member {
+                priority,
+                weight,
+                ..Member::new(device.clone())
+            },
+            initial: 0,
+            setting: Setting {
+                device,
+                min: 0,
+                max: 1000,
+                level,
+                value: 0,
+            },
+        }
+    }
+
+    fn levels(actuators: &[Actuator]) -> Vec<f64> {
+        actuators.iter().map(|a| a.setting.level).collect()
+    }
+
+    fn assert_levels(actuators: &[Actuator], expected: &[f64]) {
+        let r = levels(actuators);
+        assert!(
+            r.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-9),
+            "{:?} != {:?}",
+            r,
+            expected
+        );
+    }
+
+    #[test]
+    fn distribute_by_weight() {
+        let mut a = [actuator(0, 0, 1., 1.), actuator(1, 0, 3., 1.)];
+        distribute(&mut a, -0.4);
+        assert_levels(&a, &[0.9, 0.7]);
+    }
+
+    #[test]
+    fn distribute_carries_over_within_priority() {
+        // The first member bottoms out, so the second takes the rest.
+        let mut a = [actuator(0, 0, 1., 0.1), actuator(1, 0, 1., 1.)];
+        distribute(&mut a, -0.6);
+        assert_levels(&a, &[0., 0.5]);
+    }
+
+    #[test]
+    fn distribute_throttles_low_priority_first() {
+        let mut a = [actuator(0, 1, 1., 1.), actuator(1, 0, 1., 0.3)];
+        distribute(&mut a, -0.5);
+        assert_levels(&a, &[0.8, 0.]);
+    }
+
+    #[test]
+    fn distribute_relaxes_high_priority_first() {
+        let mut a = [actuator(0, 1, 1., 0.6), actuator(1, 0, 1., 0.)];
+        distribute(&mut a, 0.5);
+        assert_levels(&a, &[1., 0.1]);
+    }
+
+    #[test]
+    fn distribute_saturates() {
+        let mut a = [actuator(0, 0, 1., 0.2), actuator(1, 1, 1., 0.)];
+        distribute(&mut a, -1.);
+        assert_levels(&a, &[0., 0.]);
+        distribute(&mut a, 5.);
+        assert_levels(&a, &[1., 1.]);
+    }
+
+    #[test]
+    fn distribute_skips_zero_weight() {
+        let mut a = [actuator(0, 0, 0., 1.), actuator(1, 0, 1., 1.)];
+        distribute(&mut a, -0.3);
+        assert_levels(&a, &[1., 0.7]);
+    }
+
+    #[test]
+    fn level_of_value() {
+        assert_eq!(level(100, 300, 200), 0.5);
+        assert_eq!(level(100, 300, 50), 0.);
+        assert_eq!(level(100, 300, 400), 1.);
+        assert_eq!(level(100, 100, 100), 1.);
+    }
+}
@@ -1,14 +1,24 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-use crate::cpu::{self, Values};
+use crate::cpu::cpuinfo::{self, CpuInfo};
+use crate::cpu::{self, CoreType, Values};
 use crate::util::cell::Cell;
 use crate::Result;
 
 #[derive(Clone, Debug)]
 pub struct Cache {
     id: u64,
+    core_type: Cell<Option<CoreType>>,
+    cpuinfo: Cell<Option<CpuInfo>>,
+    cpu_capacity: Cell<u64>,
     online: Cell<bool>,
+    core_throttle_count: Cell<u64>,
+    core_throttle_max_time_ms: Cell<u64>,
+    core_throttle_total_time_ms: Cell<u64>,
+    package_throttle_count: Cell<u64>,
+    package_throttle_max_time_ms: Cell<u64>,
+    package_throttle_total_time_ms: Cell<u64>,
 }
 
 impl Cache {
@@ -36,27 +46,123 @@ impl Cache {
         cpu::present_ids()
     }
 
+    pub fn isolated_ids() -> impl Stream<Item = Result<u64>> {
+        cpu::isolated_ids()
+    }
+
+    pub fn nohz_full_ids() -> impl Stream<Item = Result<u64>> {
+        cpu::nohz_full_ids()
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        cpu::housekeeping_ids()
+    }
+
+    pub fn kernel_max() -> impl Future<Output = Result<u64>> {
+        cpu::kernel_max()
+    }
+
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        cpu::ids_for_core_type(core_type)
+    }
+
+    pub fn is_hybrid() -> impl Future<Output = Result<bool>> {
+        cpu::is_hybrid()
+    }
+
     pub fn all() -> impl Stream<Item = Result<Self>> {
         cpu::ids().map_ok(Self::new)
     }
 
     pub fn new(id: u64) -> Self {
-        let online = Cell::default();
-        Self { id, online }
+        Self {
+            id,
+            core_type: Cell::default(),
+            cpuinfo: Cell::default(),
+            cpu_capacity: Cell::default(),
+            online: Cell::default(),
+            core_throttle_count: Cell::default(),
+            core_throttle_max_time_ms: Cell::default(),
+            core_throttle_total_time_ms: Cell::default(),
+            package_throttle_count: Cell::default(),
+            package_throttle_max_time_ms: Cell::default(),
+            package_throttle_total_time_ms: Cell::default(),
+        }
     }
 
     pub async fn clear(&self) {
-        self.online.clear().await;
+        tokio::join!(
+            self.core_type.clear(),
+            self.cpuinfo.clear(),
+            self.cpu_capacity.clear(),
+            self.online.clear(),
+            self.core_throttle_count.clear(),
+            self.core_throttle_max_time_ms.clear(),
+            self.core_throttle_total_time_ms.clear(),
+            self.package_throttle_count.clear(),
+            self.package_throttle_max_time_ms.clear(),
+            self.package_throttle_total_time_ms.clear(),
+        );
     }
 
     pub fn id(&self) -> u64 {
         self.id
     }
 
+    pub async fn core_type(&self) -> Result<Option<CoreType>> {
+        self.core_type.get_or_load(cpu::core_type(self.id)).await
+    }
+
+    pub async fn cpuinfo(&self) -> Result<Option<CpuInfo>> {
+        self.cpuinfo.get_or_load(cpuinfo::info(self.id)).await
+    }
+
+    pub async fn cpu_capacity(&self) -> Result<u64> {
+        self.cpu_capacity
+            .get_or_load(cpu::cpu_capacity(self.id))
+            .await
+    }
+
     pub async fn online(&self) -> Result<bool> {
         self.online.get_or_load(cpu::online(self.id)).await
     }
 
+    pub async fn core_throttle_count(&self) -> Result<u64> {
+        self.core_throttle_count
+            .get_or_load(cpu::core_throttle_count(self.id))
+            .await
+    }
+
+    pub async fn core_throttle_max_time_ms(&self) -> Result<u64> {
+        self.core_throttle_max_time_ms
+            .get_or_load(cpu::core_throttle_max_time_ms(self.id))
+            .await
+    }
+
+    pub async fn core_throttle_total_time_ms(&self) -> Result<u64> {
+        self.core_throttle_total_time_ms
+            .get_or_load(cpu::core_throttle_total_time_ms(self.id))
+            .await
+    }
+
+    pub async fn package_throttle_count(&self) -> Result<u64> {
+        self.package_throttle_count
+            .get_or_load(cpu::package_throttle_count(self.id))
+            .await
+    }
+
+    pub async fn package_throttle_max_time_ms(&self) -> Result<u64> {
+        self.package_throttle_max_time_ms
+            .get_or_load(cpu::package_throttle_max_time_ms(self.id))
+            .await
+    }
+
+    pub async fn package_throttle_total_time_ms(&self) -> Result<u64> {
+        self.package_throttle_total_time_ms
+            .get_or_load(cpu::package_throttle_total_time_ms(self.id))
+            .await
+    }
+
     pub async fn set_online(&self, v: bool) -> Result<()> {
         self.online.clear_if_ok(cpu::set_online(self.id, v)).await
     }
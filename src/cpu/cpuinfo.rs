@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::cpu::path;
+use crate::util::sysfs;
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CpuInfo {
+    pub vendor_id: Option<String>,
+    pub cpu_family: Option<u64>,
+    pub model: Option<u64>,
+    pub stepping: Option<u64>,
+    pub model_name: Option<String>,
+    pub microcode: Option<u64>,
+    pub mhz: Option<f64>,
+    pub flags: Vec<String>,
+    pub bugs: Vec<String>,
+    pub other: BTreeMap<String, String>,
+}
+
+impl CpuInfo {
+    pub fn has_flag(&self, flag: &str) -> bool {
+        self.flags.iter().any(|f| f == flag)
+    }
+
+    pub fn has_bug(&self, bug: &str) -> bool {
+        self.bugs.iter().any(|b| b == bug)
+    }
+
+    pub fn is_intel(&self) -> bool {
+        self.vendor_id.as_deref() == Some("GenuineIntel")
+    }
+
+    pub fn is_amd(&self) -> bool {
+        self.vendor_id.as_deref() == Some("AuthenticAMD")
+    }
+
+    pub fn has_hwp(&self) -> bool {
+        self.has_flag("hwp")
+    }
+
+    pub fn has_hwp_epp(&self) -> bool {
+        self.has_flag("hwp_epp")
+    }
+}
+
+fn parse_u64(path: &Path, key: &str, value: &str) -> Result<u64> {
+    let r = match value.strip_prefix("0x") {
+        Some(v) => u64::from_str_radix(v, 16),
+        None => value.parse::<u64>(),
+    };
+    r.map_err(|_| Error::sysfs_parse(path, "cpuinfo: u64", format!("{}: {}", key, value)))
+}
+
+fn parse_list(value: &str) -> Vec<String> {
+    value.split_whitespace().map(String::from).collect()
+}
+
+fn parse(path: &Path, s: &str) -> Result<BTreeMap<u64, CpuInfo>> {
+    let mut r = BTreeMap::new();
+    for block in s.split("\n\n") {
+        let mut id = None;
+        let mut info = CpuInfo::default();
+        for line in block.lines() {
+            let (key, value) = match line.split_once(':') {
+                Some((k, v)) => (k.trim(), v.trim()),
+                None => continue,
+            };
+            match key {
+                "processor" => id = Some(parse_u64(path, key, value)?),
+                "vendor_id" => info.vendor_id = Some(value.to_string()),
+                "cpu family" => info.cpu_family = Some(parse_u64(path, key, value)?),
+                "model" => info.model = Some(parse_u64(path, key, value)?),
+                "stepping" => info.stepping = Some(parse_u64(path, key, value)?),
+                "model name" => info.model_name = Some(value.to_string()),
+                "microcode" => info.microcode = Some(parse_u64(path, key, value)?),
+                "cpu MHz" => {
+                    let v = value.parse::<f64>().map_err(|_| {
+                        Error::sysfs_parse(path, "cpuinfo: f64", format!("{}: {}", key, value))
+                    })?;
+                    info.mhz = Some(v);
+                },
+                "flags" | "Features" => info.flags = parse_list(value),
+                "bugs" => info.bugs = parse_list(value),
+                _ => {
+                    info.other.insert(key.to_string(), value.to_string());
+                },
+            }
+        }
+        if let Some(id) = id {
+            r.insert(id, info);
+        }
+    }
+    Ok(r)
+}
+
+pub async fn cpuinfo() -> Result<BTreeMap<u64, CpuInfo>> {
+    let path = path::proc_cpuinfo();
+    let s = sysfs::read_string(&path).await?;
+    parse(&path, &s)
+}
+
+pub async fn info(id: u64) -> Result<Option<CpuInfo>> {
+    Ok(cpuinfo().await?.remove(&id))
+}
@@ -1,9 +1,15 @@
 #[cfg(feature = "cache")]
 mod cache;
+pub mod cpuinfo;
 pub(crate) mod path;
+pub mod stat;
+pub mod throttle;
 mod values;
 
-use futures::stream::Stream;
+use std::fmt::Display;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
 
 #[cfg(feature = "cache")]
 pub use crate::cpu::cache::Cache;
@@ -11,6 +17,51 @@ pub use crate::cpu::values::Values;
 use crate::util::sysfs;
 use crate::Result;
 
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CoreType {
+    Performance,
+    Efficiency,
+}
+
+impl Display for CoreType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Performance => write!(f, "performance"),
+            Self::Efficiency => write!(f, "efficiency"),
+        }
+    }
+}
+
+// Intel hybrid parts register separate `cpu_core` and `cpu_atom` pmus, each
+// listing its cpus. Elsewhere (arm big.LITTLE) we fall back to comparing
+// `cpu_capacity`, treating the highest-capacity cpus as performance cores.
+fn has_core_ids() -> bool {
+    path::core_ids().is_file() && path::atom_ids().is_file()
+}
+
+async fn cpu_capacities() -> Result<Vec<(u64, u64)>> {
+    ids()
+        .try_filter(|id| futures::future::ready(path::cpu_capacity(*id).is_file()))
+        .and_then(|id| async move { Ok((id, cpu_capacity(id).await?)) })
+        .try_collect()
+        .await
+}
+
+// Only the lowest capacity counts as efficiency, so on systems with three or
+// more tiers the middle cores are performance cores, as they are on Intel's
+// core and atom split.
+fn core_type_for_capacity(capacities: &[(u64, u64)], capacity: u64) -> Option<CoreType> {
+    let max = capacities.iter().map(|(_, c)| *c).max()?;
+    let min = capacities.iter().map(|(_, c)| *c).min()?;
+    if min == max {
+        None
+    } else if capacity == min {
+        Some(CoreType::Efficiency)
+    } else {
+        Some(CoreType::Performance)
+    }
+}
+
 pub async fn available() -> Result<bool> {
     Ok(path::root().is_dir())
 }
@@ -39,10 +90,158 @@ pub fn possible_ids() -> impl Stream<Item = Result<u64>> {
     sysfs::read_indices(&path::possible_ids())
 }
 
+pub fn isolated_ids() -> impl Stream<Item = Result<u64>> {
+    sysfs::read_indices(&path::isolated_ids())
+}
+
+pub fn nohz_full_ids() -> impl Stream<Item = Result<u64>> {
+    sysfs::read_indices(&path::nohz_full_ids())
+}
+
+pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+    try_stream! {
+        let mut excluded: Vec<_> = isolated_ids().try_collect().await?;
+        if path::nohz_full_ids().is_file() {
+            let nohz_full: Vec<_> = nohz_full_ids().try_collect().await?;
+            excluded.extend(nohz_full);
+        }
+        for await id in ids() {
+            let id = id?;
+            if !excluded.contains(&id) {
+                yield id;
+            }
+        }
+    }
+}
+
+pub async fn kernel_max() -> Result<u64> {
+    sysfs::read_u64(&path::kernel_max()).await
+}
+
+pub fn core_ids() -> impl Stream<Item = Result<u64>> {
+    sysfs::read_indices(&path::core_ids())
+}
+
+pub fn atom_ids() -> impl Stream<Item = Result<u64>> {
+    sysfs::read_indices(&path::atom_ids())
+}
+
+pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+    try_stream! {
+        if has_core_ids() {
+            let path = match core_type {
+                CoreType::Performance => path::core_ids(),
+                CoreType::Efficiency => path::atom_ids(),
+            };
+            for await id in sysfs::read_indices(&path) {
+                yield id?;
+            }
+        } else {
+            let capacities = cpu_capacities().await?;
+            for (id, capacity) in capacities.iter().copied() {
+                if Some(core_type) == core_type_for_capacity(&capacities, capacity) {
+                    yield id;
+                }
+            }
+        }
+    }
+}
+
+pub async fn is_hybrid() -> Result<bool> {
+    let r = if has_core_ids() {
+        true
+    } else {
+        let capacities = cpu_capacities().await?;
+        capacities.iter().any(|(_, c)| *c != capacities[0].1)
+    };
+    Ok(r)
+}
+
+pub async fn core_type(id: u64) -> Result<Option<CoreType>> {
+    let r = if has_core_ids() {
+        let core: Vec<_> = core_ids().try_collect().await?;
+        let atom: Vec<_> = atom_ids().try_collect().await?;
+        if core.contains(&id) {
+            Some(CoreType::Performance)
+        } else if atom.contains(&id) {
+            Some(CoreType::Efficiency)
+        } else {
+            None
+        }
+    } else if path::cpu_capacity(id).is_file() {
+        let capacities = cpu_capacities().await?;
+        core_type_for_capacity(&capacities, cpu_capacity(id).await?)
+    } else {
+        None
+    };
+    Ok(r)
+}
+
+pub async fn cpu_capacity(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::cpu_capacity(id)).await
+}
+
 pub async fn online(id: u64) -> Result<bool> {
     sysfs::read_bool(&path::online(id)).await
 }
 
+pub async fn core_throttle_count(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::core_throttle_count(id)).await
+}
+
+pub async fn core_throttle_max_time_ms(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::core_throttle_max_time_ms(id)).await
+}
+
+pub async fn core_throttle_total_time_ms(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::core_throttle_total_time_ms(id)).await
+}
+
+pub async fn package_throttle_count(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::package_throttle_count(id)).await
+}
+
+pub async fn package_throttle_max_time_ms(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::package_throttle_max_time_ms(id)).await
+}
+
+pub async fn package_throttle_total_time_ms(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::package_throttle_total_time_ms(id)).await
+}
+
 pub async fn set_online(id: u64, v: bool) -> Result<()> {
     sysfs::write_bool(&path::online(id), v).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn core_type_two_tiers() {
+        let c = [(0, 1024), (1, 1024), (2, 512), (3, 512)];
+        assert_eq!(
+            core_type_for_capacity(&c, 1024),
+            Some(CoreType::Performance)
+        );
+        assert_eq!(core_type_for_capacity(&c, 512), Some(CoreType::Efficiency));
+    }
+
+    #[test]
+    fn core_type_three_tiers() {
+        let c = [(0, 1024), (1, 870), (2, 870), (3, 280), (4, 280)];
+        assert_eq!(
+            core_type_for_capacity(&c, 1024),
+            Some(CoreType::Performance)
+        );
+        assert_eq!(core_type_for_capacity(&c, 870), Some(CoreType::Performance));
+        assert_eq!(core_type_for_capacity(&c, 280), Some(CoreType::Efficiency));
+    }
+
+    #[test]
+    fn core_type_uniform() {
+        let c = [(0, 1024), (1, 1024)];
+        assert_eq!(core_type_for_capacity(&c, 1024), None);
+        assert_eq!(core_type_for_capacity(&[], 1024), None);
+    }
+}
@@ -1,5 +1,13 @@
 use std::path::PathBuf;
 
+pub(crate) fn proc_cpuinfo() -> PathBuf {
+    PathBuf::from("/proc/cpuinfo")
+}
+
+pub(crate) fn proc_stat() -> PathBuf {
+    PathBuf::from("/proc/stat")
+}
+
 pub(crate) fn root() -> PathBuf {
     PathBuf::from("/sys/devices/system/cpu")
 }
@@ -32,6 +40,62 @@ pub(crate) fn possible_ids() -> PathBuf {
     root_attr("possible")
 }
 
+pub(crate) fn isolated_ids() -> PathBuf {
+    root_attr("isolated")
+}
+
+pub(crate) fn nohz_full_ids() -> PathBuf {
+    root_attr("nohz_full")
+}
+
+pub(crate) fn kernel_max() -> PathBuf {
+    root_attr("kernel_max")
+}
+
 pub(crate) fn online(id: u64) -> PathBuf {
     cpu_attr(id, "online")
 }
+
+pub(crate) fn cpu_capacity(id: u64) -> PathBuf {
+    cpu_attr(id, "cpu_capacity")
+}
+
+pub(crate) fn core_ids() -> PathBuf {
+    PathBuf::from("/sys/devices/cpu_core/cpus")
+}
+
+pub(crate) fn atom_ids() -> PathBuf {
+    PathBuf::from("/sys/devices/cpu_atom/cpus")
+}
+
+pub(crate) fn thermal_throttle(id: u64) -> PathBuf {
+    cpu_attr(id, "thermal_throttle")
+}
+
+pub(crate) fn thermal_throttle_attr(id: u64, a: &str) -> PathBuf {
+    thermal_throttle(id).join(a)
+}
+
+pub(crate) fn core_throttle_count(id: u64) -> PathBuf {
+    thermal_throttle_attr(id, "core_throttle_count")
+}
+
+pub(crate) fn core_throttle_max_time_ms(id: u64) -> PathBuf {
+    thermal_throttle_attr(id, "core_throttle_max_time_ms")
+}
+
+pub(crate) fn core_throttle_total_time_ms(id: u64) -> PathBuf {
+    thermal_throttle_attr(id, "core_throttle_total_time_ms")
+}
+
+pub(crate) fn package_throttle_count(id: u64) -> PathBuf {
+    thermal_throttle_attr(id, "package_throttle_count")
+}
+
+pub(crate) fn package_throttle_max_time_ms(id: u64) -> PathBuf {
+    thermal_throttle_attr(id, "package_throttle_max_time_ms")
+}
+
+pub(crate) fn package_throttle_total_time_ms(id: u64) -> PathBuf {
+    thermal_throttle_attr(id, "package_throttle_total_time_ms")
+}
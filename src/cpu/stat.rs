@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::stream::Stream;
+use tokio::time::MissedTickBehavior;
+
+use crate::cpu::path;
+use crate::util::sysfs;
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Times {
+    pub user: u64,
+    pub nice: u64,
+    pub system: u64,
+    pub idle: u64,
+    pub iowait: u64,
+    pub irq: u64,
+    pub softirq: u64,
+    pub steal: u64,
+}
+
+impl Times {
+    // Fields after `steal` (guest time, already included in `user` and
+    // `nice`) are ignored.
+    fn parse(path: &Path, line: &str, fields: &[&str]) -> Result<Self> {
+        let mut v = [0; 8];
+        if fields.len() < v.len() {
+            return Err(Error::sysfs_parse(path, "stat: cpu", line));
+        }
+        for (v, f) in v.iter_mut().zip(fields) {
+            *v = f
+                .parse::<u64>()
+                .map_err(|_| Error::sysfs_parse(path, "stat: cpu", line))?;
+        }
+        let [user, nice, system, idle, iowait, irq, softirq, steal] = v;
+        Ok(Self {
+            user,
+            nice,
+            system,
+            idle,
+            iowait,
+            irq,
+            softirq,
+            steal,
+        })
+    }
+
+    pub fn idle_total(&self) -> u64 {
+        self.idle + self.iowait
+    }
+
+    pub fn busy_total(&self) -> u64 {
+        self.user + self.nice + self.system + self.irq + self.softirq + self.steal
+    }
+
+    pub fn total(&self) -> u64 {
+        self.idle_total() + self.busy_total()
+    }
+
+    pub fn load_since(&self, prev: &Times) -> Load {
+        let total = self.total().saturating_sub(prev.total());
+        let pct = |cur: u64, prev: u64| {
+            if total == 0 {
+                0.
+            } else {
+                cur.saturating_sub(prev) as f64 / total as f64 * 100.
+            }
+        };
+        Load {
+            user: pct(self.user, prev.user),
+            nice: pct(self.nice, prev.nice),
+            system: pct(self.system, prev.system),
+            idle: pct(self.idle, prev.idle),
+            iowait: pct(self.iowait, prev.iowait),
+            irq: pct(self.irq, prev.irq),
+            softirq: pct(self.softirq, prev.softirq),
+            steal: pct(self.steal, prev.steal),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stat {
+    pub total: Times,
+    pub cpus: BTreeMap<u64, Times>,
+}
+
+impl Stat {
+    fn parse(path: &Path, s: &str) -> Result<Self> {
+        let mut r = Self::default();
+        for line in s.lines() {
+            let fields: Vec<_> = line.split_whitespace().collect();
+            match fields.split_first() {
+                Some((&"cpu", fields)) => r.total = Times::parse(path, line, fields)?,
+                Some((name, fields)) if name.starts_with("cpu") => {
+                    let id = name[3..]
+                        .parse::<u64>()
+                        .map_err(|_| Error::sysfs_parse(path, "stat: cpu id", line))?;
+                    r.cpus.insert(id, Times::parse(path, line, fields)?);
+                },
+                _ => {},
+            }
+        }
+        Ok(r)
+    }
+
+    pub fn utilization_since(&self, prev: &Stat) -> Utilization {
+        let total = self.total.load_since(&prev.total);
+        let cpus = self
+            .cpus
+            .iter()
+            .filter_map(|(id, cur)| prev.cpus.get(id).map(|prev| (*id, cur.load_since(prev))))
+            .collect();
+        Utilization { total, cpus }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Load {
+    pub user: f64,
+    pub nice: f64,
+    pub system: f64,
+    pub idle: f64,
+    pub iowait: f64,
+    pub irq: f64,
+    pub softirq: f64,
+    pub steal: f64,
+}
+
+impl Load {
+    pub fn busy(&self) -> f64 {
+        self.user + self.nice + self.system + self.irq + self.softirq + self.steal
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Utilization {
+    pub total: Load,
+    pub cpus: BTreeMap<u64, Load>,
+}
+
+pub async fn stat() -> Result<Stat> {
+    let path = path::proc_stat();
+    let s = sysfs::read_string(&path).await?;
+    Stat::parse(&path, &s)
+}
+
+pub async fn times(id: u64) -> Result<Option<Times>> {
+    Ok(stat().await?.cpus.remove(&id))
+}
+
+pub fn sampler(interval: Duration) -> impl Stream<Item = Result<Utilization>> {
+    try_stream! {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval.tick().await;
+        let mut prev = stat().await?;
+        loop {
+            interval.tick().await;
+            let cur = stat().await?;
+            yield cur.utilization_since(&prev);
+            prev = cur;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAT: &str = "cpu  400 20 100 3000 50 10 5 0 0 0
+cpu0 300 20 60 1400 30 10 5 0 0 0
+cpu1 100 0 40 1600 20 0 0 0 0 0
+intr 12345 0 0
+ctxt 67890
+btime 1700000000
+processes 4242
+";
+
+    fn times(v: [u64; 8]) -> Times {
+        let [user, nice, system, idle, iowait, irq, softirq, steal] = v;
+        Times {
+            user,
+            nice,
+            system,
+            idle,
+            iowait,
+            irq,
+            softirq,
+            steal,
+        }
+    }
+
+    #[test]
+    fn stat_parse() {
+        let r = Stat::parse(Path::new("stat"), STAT).unwrap();
+        assert_eq!(r.total, times([400, 20, 100, 3000, 50, 10, 5, 0]));
+        assert_eq!(r.cpus.len(), 2);
+        assert_eq!(r.cpus[&0], times([300, 20, 60, 1400, 30, 10, 5, 0]));
+        assert_eq!(r.cpus[&1], times([100, 0, 40, 1600, 20, 0, 0, 0]));
+        assert_eq!(r.total.total(), 3585);
+        assert_eq!(r.total.idle_total(), 3050);
+        assert_eq!(r.total.busy_total(), 535);
+    }
+
+    #[test]
+    fn stat_parse_invalid() {
+        let path = Path::new("stat");
+        assert!(Stat::parse(path, "cpu  400 20 100 3000 50 10 5\n").is_err());
+        assert!(Stat::parse(path, "cpu0 300 20 60 x 30 10 5 0\n").is_err());
+        assert!(Stat::parse(path, "cpux 300 20 60 1400 30 10 5 0\n").is_err());
+    }
+
+    fn assert_pct(v: f64, expected: f64) {
+        assert!((v - expected).abs() < 1e-9, "{} != {}", v, expected);
+    }
+
+    #[test]
+    fn utilization_since() {
+        let prev = Stat::parse(Path::new("stat"), STAT).unwrap();
+        let mut cur = prev.clone();
+        cur.total = times([460, 20, 120, 3100, 60, 10, 15, 0]);
+        cur.cpus
+            .insert(0, times([350, 20, 70, 1435, 30, 10, 10, 0]));
+        cur.cpus.insert(2, times([1, 1, 1, 1, 1, 1, 1, 1]));
+        let r = cur.utilization_since(&prev);
+        assert_pct(r.total.user, 30.);
+        assert_pct(r.total.system, 10.);
+        assert_pct(r.total.idle, 50.);
+        assert_pct(r.total.iowait, 5.);
+        assert_pct(r.total.softirq, 5.);
+        assert_pct(r.total.busy(), 45.);
+        // Cpus missing from either sample are left out.
+        assert_eq!(r.cpus.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_pct(r.cpus[&0].user, 50.);
+        assert_pct(r.cpus[&0].idle, 35.);
+        assert_pct(r.cpus[&0].busy(), 65.);
+        // No elapsed ticks.
+        assert_eq!(r.cpus[&1], Load::default());
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
+use tokio::time::MissedTickBehavior;
+
+use crate::cpu::{self, path};
+use crate::{Error, Result};
+
+// The `*_time_ms` attributes were added in linux 5.18 and the `package_*`
+// attributes need package thermal status support, so those are `None` when
+// missing.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Counters {
+    pub core_throttle_count: u64,
+    pub core_throttle_max_time_ms: Option<u64>,
+    pub core_throttle_total_time_ms: Option<u64>,
+    pub package_throttle_count: Option<u64>,
+    pub package_throttle_max_time_ms: Option<u64>,
+    pub package_throttle_total_time_ms: Option<u64>,
+}
+
+fn delta(cur: Option<u64>, prev: Option<u64>) -> Option<u64> {
+    cur.zip(prev).map(|(c, p)| c.saturating_sub(p))
+}
+
+impl Counters {
+    // The `*_max_time_ms` values are high-water marks rather than counters, so
+    // they are carried over as-is.
+    pub fn delta_since(&self, prev: &Counters) -> Counters {
+        Counters {
+            core_throttle_count: self
+                .core_throttle_count
+                .saturating_sub(prev.core_throttle_count),
+            core_throttle_max_time_ms: self.core_throttle_max_time_ms,
+            core_throttle_total_time_ms: delta(
+                self.core_throttle_total_time_ms,
+                prev.core_throttle_total_time_ms,
+            ),
+            package_throttle_count: delta(self.package_throttle_count, prev.package_throttle_count),
+            package_throttle_max_time_ms: self.package_throttle_max_time_ms,
+            package_throttle_total_time_ms: delta(
+                self.package_throttle_total_time_ms,
+                prev.package_throttle_total_time_ms,
+            ),
+        }
+    }
+
+    pub fn is_throttled(&self) -> bool {
+        self.core_throttle_count > 0 || self.package_throttle_count.unwrap_or(0) > 0
+    }
+}
+
+async fn optional(f: impl Future<Output = Result<u64>>) -> Result<Option<u64>> {
+    match f.await {
+        Ok(v) => Ok(Some(v)),
+        Err(Error::SysfsIo { source, .. }) if source.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn available() -> Result<bool> {
+    Ok(path::thermal_throttle(0).is_dir())
+}
+
+pub async fn exists(id: u64) -> Result<bool> {
+    Ok(path::thermal_throttle(id).is_dir())
+}
+
+pub fn ids() -> impl Stream<Item = Result<u64>> {
+    cpu::online_ids().try_filter(|id| futures::future::ready(path::thermal_throttle(*id).is_dir()))
+}
+
+pub async fn counters(id: u64) -> Result<Counters> {
+    let (
+        core_throttle_count,
+        core_throttle_max_time_ms,
+        core_throttle_total_time_ms,
+        package_throttle_count,
+        package_throttle_max_time_ms,
+        package_throttle_total_time_ms,
+    ) = tokio::try_join!(
+        cpu::core_throttle_count(id),
+        optional(cpu::core_throttle_max_time_ms(id)),
+        optional(cpu::core_throttle_total_time_ms(id)),
+        optional(cpu::package_throttle_count(id)),
+        optional(cpu::package_throttle_max_time_ms(id)),
+        optional(cpu::package_throttle_total_time_ms(id)),
+    )?;
+    Ok(Counters {
+        core_throttle_count,
+        core_throttle_max_time_ms,
+        core_throttle_total_time_ms,
+        package_throttle_count,
+        package_throttle_max_time_ms,
+        package_throttle_total_time_ms,
+    })
+}
+
+pub async fn all_counters() -> Result<BTreeMap<u64, Counters>> {
+    ids()
+        .and_then(|id| async move { Ok((id, counters(id).await?)) })
+        .try_collect()
+        .await
+}
+
+pub fn sampler(interval: Duration) -> impl Stream<Item = Result<BTreeMap<u64, Counters>>> {
+    try_stream! {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval.tick().await;
+        let mut prev = all_counters().await?;
+        loop {
+            interval.tick().await;
+            let cur = all_counters().await?;
+            let r = cur
+                .iter()
+                .filter_map(|(id, c)| prev.get(id).map(|p| (*id, c.delta_since(p))))
+                .collect();
+            yield r;
+            prev = cur;
+        }
+    }
+}
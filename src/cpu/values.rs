@@ -1,7 +1,8 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-use crate::cpu;
+use crate::cpu::cpuinfo::{self, CpuInfo};
+use crate::cpu::{self, CoreType};
 #[cfg(feature = "cache")]
 use crate::cpu::Cache;
 use crate::Result;
@@ -36,6 +37,30 @@ impl Values {
         cpu::present_ids()
     }
 
+    pub fn isolated_ids() -> impl Stream<Item = Result<u64>> {
+        cpu::isolated_ids()
+    }
+
+    pub fn nohz_full_ids() -> impl Stream<Item = Result<u64>> {
+        cpu::nohz_full_ids()
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        cpu::housekeeping_ids()
+    }
+
+    pub fn kernel_max() -> impl Future<Output = Result<u64>> {
+        cpu::kernel_max()
+    }
+
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        cpu::ids_for_core_type(core_type)
+    }
+
+    pub fn is_hybrid() -> impl Future<Output = Result<bool>> {
+        cpu::is_hybrid()
+    }
+
     pub fn all() -> impl Stream<Item = Result<Self>> {
         cpu::ids().map_ok(Self::new)
     }
@@ -48,10 +73,46 @@ impl Values {
         self.id
     }
 
+    pub fn core_type(&self) -> impl Future<Output = Result<Option<CoreType>>> {
+        cpu::core_type(self.id)
+    }
+
+    pub fn cpuinfo(&self) -> impl Future<Output = Result<Option<CpuInfo>>> {
+        cpuinfo::info(self.id)
+    }
+
+    pub fn cpu_capacity(&self) -> impl Future<Output = Result<u64>> {
+        cpu::cpu_capacity(self.id)
+    }
+
     pub fn online(&self) -> impl Future<Output = Result<bool>> {
         cpu::online(self.id)
     }
 
+    pub fn core_throttle_count(&self) -> impl Future<Output = Result<u64>> {
+        cpu::core_throttle_count(self.id)
+    }
+
+    pub fn core_throttle_max_time_ms(&self) -> impl Future<Output = Result<u64>> {
+        cpu::core_throttle_max_time_ms(self.id)
+    }
+
+    pub fn core_throttle_total_time_ms(&self) -> impl Future<Output = Result<u64>> {
+        cpu::core_throttle_total_time_ms(self.id)
+    }
+
+    pub fn package_throttle_count(&self) -> impl Future<Output = Result<u64>> {
+        cpu::package_throttle_count(self.id)
+    }
+
+    pub fn package_throttle_max_time_ms(&self) -> impl Future<Output = Result<u64>> {
+        cpu::package_throttle_max_time_ms(self.id)
+    }
+
+    pub fn package_throttle_total_time_ms(&self) -> impl Future<Output = Result<u64>> {
+        cpu::package_throttle_total_time_ms(self.id)
+    }
+
     pub fn set_online(&self, v: bool) -> impl Future<Output = Result<()>> {
         cpu::set_online(self.id, v)
     }
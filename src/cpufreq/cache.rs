@@ -1,6 +1,11 @@
+use std::collections::BTreeMap;
+
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
+use crate::cpu::CoreType;
+use crate::cpufreq::governor::Tunables;
+use crate::cpufreq::stats::{self, TransTable};
 use crate::cpufreq::{self, Values};
 use crate::util::cell::Cell;
 use crate::Result;
@@ -8,14 +13,26 @@ use crate::Result;
 #[derive(Clone, Debug)]
 pub struct Cache {
     id: u64,
+    affected_cpus: Cell<Vec<u64>>,
+    related_cpus: Cell<Vec<u64>>,
+    bios_limit: Cell<u64>,
+    boost: Cell<bool>,
     cpuinfo_max_freq: Cell<u64>,
     cpuinfo_min_freq: Cell<u64>,
+    cpuinfo_transition_latency: Cell<u64>,
+    scaling_available_frequencies: Cell<Vec<u64>>,
+    scaling_boost_frequencies: Cell<Vec<u64>>,
     scaling_cur_freq: Cell<u64>,
     scaling_driver: Cell<String>,
     scaling_governor: Cell<String>,
     scaling_available_governors: Cell<Vec<String>>,
     scaling_max_freq: Cell<u64>,
     scaling_min_freq: Cell<u64>,
+    scaling_setspeed: Cell<u64>,
+    governor_tunables: Cell<Option<Tunables>>,
+    time_in_state: Cell<BTreeMap<u64, u64>>,
+    total_trans: Cell<u64>,
+    trans_table: Cell<TransTable>,
 }
 
 impl Cache {
@@ -31,6 +48,26 @@ impl Cache {
         cpufreq::ids()
     }
 
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        cpufreq::ids_for_core_type(core_type)
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        cpufreq::housekeeping_ids()
+    }
+
+    pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        cpufreq::non_housekeeping_ids()
+    }
+
+    pub fn id_for_cpu(cpu: u64) -> impl Future<Output = Result<Option<u64>>> {
+        cpufreq::id_for_cpu(cpu)
+    }
+
+    pub async fn for_cpu(cpu: u64) -> Result<Option<Self>> {
+        Ok(cpufreq::id_for_cpu(cpu).await?.map(Self::new))
+    }
+
     pub fn all() -> impl Stream<Item = Result<Self>> {
         cpufreq::ids().map_ok(Self::new)
     }
@@ -38,14 +75,26 @@ impl Cache {
     pub fn new(id: u64) -> Self {
         Self {
             id,
+            affected_cpus: Cell::default(),
+            related_cpus: Cell::default(),
+            bios_limit: Cell::default(),
+            boost: Cell::default(),
             cpuinfo_max_freq: Cell::default(),
             cpuinfo_min_freq: Cell::default(),
+            cpuinfo_transition_latency: Cell::default(),
+            scaling_available_frequencies: Cell::default(),
+            scaling_boost_frequencies: Cell::default(),
             scaling_cur_freq: Cell::default(),
             scaling_driver: Cell::default(),
             scaling_governor: Cell::default(),
             scaling_available_governors: Cell::default(),
             scaling_max_freq: Cell::default(),
             scaling_min_freq: Cell::default(),
+            scaling_setspeed: Cell::default(),
+            governor_tunables: Cell::default(),
+            time_in_state: Cell::default(),
+            total_trans: Cell::default(),
+            trans_table: Cell::default(),
         }
     }
 
@@ -55,17 +104,51 @@ impl Cache {
 
     pub async fn clear(&self) {
         tokio::join!(
+            self.affected_cpus.clear(),
+            self.related_cpus.clear(),
+            self.bios_limit.clear(),
+            self.boost.clear(),
             self.cpuinfo_max_freq.clear(),
             self.cpuinfo_min_freq.clear(),
+            self.cpuinfo_transition_latency.clear(),
+            self.scaling_available_frequencies.clear(),
+            self.scaling_boost_frequencies.clear(),
             self.scaling_cur_freq.clear(),
             self.scaling_driver.clear(),
             self.scaling_governor.clear(),
             self.scaling_available_governors.clear(),
             self.scaling_max_freq.clear(),
             self.scaling_min_freq.clear(),
+            self.scaling_setspeed.clear(),
+            self.governor_tunables.clear(),
+            self.time_in_state.clear(),
+            self.total_trans.clear(),
+            self.trans_table.clear(),
         );
     }
 
+    pub async fn affected_cpus(&self) -> Result<Vec<u64>> {
+        self.affected_cpus
+            .get_or_load(cpufreq::affected_cpus(self.id))
+            .await
+    }
+
+    pub async fn related_cpus(&self) -> Result<Vec<u64>> {
+        self.related_cpus
+            .get_or_load(cpufreq::related_cpus(self.id))
+            .await
+    }
+
+    pub async fn bios_limit(&self) -> Result<u64> {
+        self.bios_limit
+            .get_or_load(cpufreq::bios_limit(self.id))
+            .await
+    }
+
+    pub async fn boost(&self) -> Result<bool> {
+        self.boost.get_or_load(cpufreq::policy_boost(self.id)).await
+    }
+
     pub async fn cpuinfo_max_freq(&self) -> Result<u64> {
         self.cpuinfo_max_freq
             .get_or_load(cpufreq::cpuinfo_max_freq(self.id))
@@ -78,6 +161,24 @@ impl Cache {
             .await
     }
 
+    pub async fn cpuinfo_transition_latency(&self) -> Result<u64> {
+        self.cpuinfo_transition_latency
+            .get_or_load(cpufreq::cpuinfo_transition_latency(self.id))
+            .await
+    }
+
+    pub async fn scaling_available_frequencies(&self) -> Result<Vec<u64>> {
+        self.scaling_available_frequencies
+            .get_or_load(cpufreq::scaling_available_frequencies(self.id))
+            .await
+    }
+
+    pub async fn scaling_boost_frequencies(&self) -> Result<Vec<u64>> {
+        self.scaling_boost_frequencies
+            .get_or_load(cpufreq::scaling_boost_frequencies(self.id))
+            .await
+    }
+
     pub async fn scaling_cur_freq(&self) -> Result<u64> {
         self.scaling_cur_freq
             .get_or_load(cpufreq::scaling_cur_freq(self.id))
@@ -114,10 +215,59 @@ impl Cache {
             .await
     }
 
+    pub async fn scaling_setspeed(&self) -> Result<u64> {
+        self.scaling_setspeed
+            .get_or_load(cpufreq::scaling_setspeed(self.id))
+            .await
+    }
+
+    pub async fn governor_tunables(&self) -> Result<Option<Tunables>> {
+        self.governor_tunables
+            .get_or_load(Tunables::for_policy(self.id))
+            .await
+    }
+
+    pub async fn time_in_state(&self) -> Result<BTreeMap<u64, u64>> {
+        self.time_in_state
+            .get_or_load(stats::time_in_state(self.id))
+            .await
+    }
+
+    pub async fn total_trans(&self) -> Result<u64> {
+        self.total_trans
+            .get_or_load(stats::total_trans(self.id))
+            .await
+    }
+
+    pub async fn trans_table(&self) -> Result<TransTable> {
+        self.trans_table
+            .get_or_load(stats::trans_table(self.id))
+            .await
+    }
+
+    pub async fn reset_stats(&self) -> Result<()> {
+        stats::reset(self.id).await?;
+        tokio::join!(
+            self.time_in_state.clear(),
+            self.total_trans.clear(),
+            self.trans_table.clear(),
+        );
+        Ok(())
+    }
+
+    pub async fn set_boost(&self, v: bool) -> Result<()> {
+        self.boost
+            .clear_if_ok(cpufreq::set_policy_boost(self.id, v))
+            .await
+    }
+
+    // Tunables belong to the governor, so they are reloaded after a switch.
     pub async fn set_scaling_governor(&self, v: impl AsRef<str>) -> Result<()> {
         self.scaling_governor
             .clear_if_ok(cpufreq::set_scaling_governor(self.id, v.as_ref()))
-            .await
+            .await?;
+        self.governor_tunables.clear().await;
+        Ok(())
     }
 
     pub async fn set_scaling_max_freq(&self, v: u64) -> Result<()> {
@@ -131,6 +281,12 @@ impl Cache {
             .clear_if_ok(cpufreq::set_scaling_min_freq(self.id, v))
             .await
     }
+
+    pub async fn set_scaling_setspeed(&self, v: u64) -> Result<()> {
+        self.scaling_setspeed
+            .clear_if_ok(cpufreq::set_scaling_setspeed(self.id, v))
+            .await
+    }
 }
 
 impl From<Values> for Cache {
@@ -0,0 +1,185 @@
+use std::path::PathBuf;
+
+use crate::cpufreq::{self, path};
+use crate::util::sysfs;
+use crate::Result;
+
+pub const ONDEMAND: &str = "ondemand";
+pub const CONSERVATIVE: &str = "conservative";
+pub const SCHEDUTIL: &str = "schedutil";
+
+// Governor tunables live under the policy directory on platforms with
+// per-policy governors, and under the global cpufreq directory otherwise.
+fn tunables_dir(id: u64, governor: &str) -> Option<PathBuf> {
+    [
+        path::policy_governor(id, governor),
+        path::governor(governor),
+    ]
+    .into_iter()
+    .find(|p| p.is_dir())
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Tunables {
+    Ondemand(Ondemand),
+    Conservative(Conservative),
+    Schedutil(Schedutil),
+}
+
+impl Tunables {
+    pub async fn for_policy(id: u64) -> Result<Option<Self>> {
+        let governor = cpufreq::scaling_governor(id).await?;
+        let r = match governor.as_str() {
+            ONDEMAND => Ondemand::for_policy(id).map(Self::Ondemand),
+            CONSERVATIVE => Conservative::for_policy(id).map(Self::Conservative),
+            SCHEDUTIL => Schedutil::for_policy(id).map(Self::Schedutil),
+            _ => None,
+        };
+        Ok(r)
+    }
+
+    pub fn governor(&self) -> &'static str {
+        match self {
+            Self::Ondemand(_) => ONDEMAND,
+            Self::Conservative(_) => CONSERVATIVE,
+            Self::Schedutil(_) => SCHEDUTIL,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Ondemand {
+    path: PathBuf,
+}
+
+impl Ondemand {
+    pub fn for_policy(id: u64) -> Option<Self> {
+        tunables_dir(id, ONDEMAND).map(|path| Self { path })
+    }
+
+    pub async fn sampling_rate(&self) -> Result<u64> {
+        sysfs::read_u64(&path::sampling_rate(&self.path)).await
+    }
+
+    pub async fn up_threshold(&self) -> Result<u64> {
+        sysfs::read_u64(&path::up_threshold(&self.path)).await
+    }
+
+    pub async fn sampling_down_factor(&self) -> Result<u64> {
+        sysfs::read_u64(&path::sampling_down_factor(&self.path)).await
+    }
+
+    pub async fn ignore_nice_load(&self) -> Result<bool> {
+        sysfs::read_bool(&path::ignore_nice_load(&self.path)).await
+    }
+
+    pub async fn powersave_bias(&self) -> Result<u64> {
+        sysfs::read_u64(&path::powersave_bias(&self.path)).await
+    }
+
+    pub async fn io_is_busy(&self) -> Result<bool> {
+        sysfs::read_bool(&path::io_is_busy(&self.path)).await
+    }
+
+    pub async fn set_sampling_rate(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::sampling_rate(&self.path), v).await
+    }
+
+    pub async fn set_up_threshold(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::up_threshold(&self.path), v).await
+    }
+
+    pub async fn set_sampling_down_factor(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::sampling_down_factor(&self.path), v).await
+    }
+
+    pub async fn set_ignore_nice_load(&self, v: bool) -> Result<()> {
+        sysfs::write_bool(&path::ignore_nice_load(&self.path), v).await
+    }
+
+    pub async fn set_powersave_bias(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::powersave_bias(&self.path), v).await
+    }
+
+    pub async fn set_io_is_busy(&self, v: bool) -> Result<()> {
+        sysfs::write_bool(&path::io_is_busy(&self.path), v).await
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Conservative {
+    path: PathBuf,
+}
+
+impl Conservative {
+    pub fn for_policy(id: u64) -> Option<Self> {
+        tunables_dir(id, CONSERVATIVE).map(|path| Self { path })
+    }
+
+    pub async fn sampling_rate(&self) -> Result<u64> {
+        sysfs::read_u64(&path::sampling_rate(&self.path)).await
+    }
+
+    pub async fn up_threshold(&self) -> Result<u64> {
+        sysfs::read_u64(&path::up_threshold(&self.path)).await
+    }
+
+    pub async fn down_threshold(&self) -> Result<u64> {
+        sysfs::read_u64(&path::down_threshold(&self.path)).await
+    }
+
+    pub async fn sampling_down_factor(&self) -> Result<u64> {
+        sysfs::read_u64(&path::sampling_down_factor(&self.path)).await
+    }
+
+    pub async fn ignore_nice_load(&self) -> Result<bool> {
+        sysfs::read_bool(&path::ignore_nice_load(&self.path)).await
+    }
+
+    pub async fn freq_step(&self) -> Result<u64> {
+        sysfs::read_u64(&path::freq_step(&self.path)).await
+    }
+
+    pub async fn set_sampling_rate(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::sampling_rate(&self.path), v).await
+    }
+
+    pub async fn set_up_threshold(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::up_threshold(&self.path), v).await
+    }
+
+    pub async fn set_down_threshold(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::down_threshold(&self.path), v).await
+    }
+
+    pub async fn set_sampling_down_factor(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::sampling_down_factor(&self.path), v).await
+    }
+
+    pub async fn set_ignore_nice_load(&self, v: bool) -> Result<()> {
+        sysfs::write_bool(&path::ignore_nice_load(&self.path), v).await
+    }
+
+    pub async fn set_freq_step(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::freq_step(&self.path), v).await
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Schedutil {
+    path: PathBuf,
+}
+
+impl Schedutil {
+    pub fn for_policy(id: u64) -> Option<Self> {
+        tunables_dir(id, SCHEDUTIL).map(|path| Self { path })
+    }
+
+    pub async fn rate_limit_us(&self) -> Result<u64> {
+        sysfs::read_u64(&path::rate_limit_us(&self.path)).await
+    }
+
+    pub async fn set_rate_limit_us(&self, v: u64) -> Result<()> {
+        sysfs::write_u64(&path::rate_limit_us(&self.path), v).await
+    }
+}
@@ -1,15 +1,23 @@
 #[cfg(feature = "cache")]
 mod cache;
+pub mod governor;
 pub(crate) mod path;
+pub mod stats;
+pub mod userspace;
 mod values;
 
-use futures::stream::Stream;
+use async_stream::try_stream;
+use futures::future::try_join_all;
+use futures::pin_mut;
+use futures::stream::{Stream, TryStreamExt as _};
 
+use crate::cpu::{self, CoreType};
 #[cfg(feature = "cache")]
 pub use crate::cpufreq::cache::Cache;
 pub use crate::cpufreq::values::Values;
+use crate::intel_pstate;
 use crate::util::sysfs;
-use crate::Result;
+use crate::{Error, Result};
 
 pub async fn available() -> Result<bool> {
     Ok(path::root().is_dir())
@@ -23,6 +31,144 @@ pub fn ids() -> impl Stream<Item = Result<u64>> {
     sysfs::read_ids(&path::root(), "policy")
 }
 
+fn ids_for_cpus<S>(cpus: S, contained: bool) -> impl Stream<Item = Result<u64>>
+where
+    S: Stream<Item = Result<u64>>,
+{
+    try_stream! {
+        let cpus: Vec<_> = cpus.try_collect().await?;
+        for await id in ids() {
+            let id = id?;
+            let related = related_cpus(id).await?;
+            if contained == related.iter().any(|c| cpus.contains(c)) {
+                yield id;
+            }
+        }
+    }
+}
+
+pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+    ids_for_cpus(cpu::ids_for_core_type(core_type), true)
+}
+
+pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+    ids_for_cpus(cpu::housekeeping_ids(), true)
+}
+
+// Policies without a housekeeping cpu, which covers `nohz_full` as well as
+// `isolated` cpus, unlike `cpu::isolated_ids`.
+pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+    ids_for_cpus(cpu::housekeeping_ids(), false)
+}
+
+pub async fn id_for_cpu(cpu: u64) -> Result<Option<u64>> {
+    let path = path::cpu_policy(cpu);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let name = sysfs::read_link_name(&path).await?;
+    let id = name
+        .strip_prefix("policy")
+        .and_then(|v| v.parse::<u64>().ok())
+        .ok_or_else(|| Error::sysfs_parse(&path, "policy", &name))?;
+    Ok(Some(id))
+}
+
+pub async fn affected_cpus(id: u64) -> Result<Vec<u64>> {
+    sysfs::read_u64_list(&path::affected_cpus(id), ' ').await
+}
+
+pub async fn related_cpus(id: u64) -> Result<Vec<u64>> {
+    sysfs::read_u64_list(&path::related_cpus(id), ' ').await
+}
+
+pub async fn bios_limit(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::bios_limit(id)).await
+}
+
+pub async fn cpuinfo_transition_latency(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::cpuinfo_transition_latency(id)).await
+}
+
+pub async fn boost() -> Result<bool> {
+    sysfs::read_bool(&path::boost()).await
+}
+
+pub async fn set_boost(v: bool) -> Result<()> {
+    sysfs::write_bool(&path::boost(), v).await
+}
+
+pub async fn policy_boost(id: u64) -> Result<bool> {
+    sysfs::read_bool(&path::policy_boost(id)).await
+}
+
+pub async fn set_policy_boost(id: u64, v: bool) -> Result<()> {
+    sysfs::write_bool(&path::policy_boost(id), v).await
+}
+
+async fn driver() -> Result<String> {
+    let s = ids();
+    pin_mut!(s);
+    let id = s
+        .try_next()
+        .await?
+        .ok_or_else(|| Error::non_sequitor("No cpufreq policies found"))?;
+    scaling_driver(id).await
+}
+
+fn is_intel_pstate_driver(driver: &str) -> bool {
+    matches!(driver, "intel_pstate" | "intel_cpufreq")
+}
+
+fn turbo_unavailable(driver: &str) -> Error {
+    let s = format!("Turbo control not available for cpufreq driver {}", driver);
+    #[cfg(feature = "logging")]
+    log::error!("ERR {}", s);
+    Error::non_sequitor(s)
+}
+
+// Drivers such as amd-pstate only have per-policy `boost` attributes.
+async fn policy_boost_ids() -> Result<Vec<u64>> {
+    ids()
+        .try_filter(|id| futures::future::ready(path::policy_boost(*id).is_file()))
+        .try_collect()
+        .await
+}
+
+// With per-policy boost, turbo is reported as on only when every policy has
+// boost enabled.
+pub async fn turbo() -> Result<bool> {
+    let driver = driver().await?;
+    if is_intel_pstate_driver(&driver) && intel_pstate::available().await? {
+        intel_pstate::system::no_turbo().await.map(|v| !v)
+    } else if path::boost().is_file() {
+        boost().await
+    } else {
+        let ids = policy_boost_ids().await?;
+        if ids.is_empty() {
+            return Err(turbo_unavailable(&driver));
+        }
+        let r = try_join_all(ids.into_iter().map(policy_boost)).await?;
+        Ok(r.into_iter().all(|v| v))
+    }
+}
+
+pub async fn set_turbo(v: bool) -> Result<()> {
+    let driver = driver().await?;
+    if is_intel_pstate_driver(&driver) && intel_pstate::available().await? {
+        intel_pstate::system::set_no_turbo(!v).await
+    } else if path::boost().is_file() {
+        set_boost(v).await
+    } else {
+        let ids = policy_boost_ids().await?;
+        if ids.is_empty() {
+            return Err(turbo_unavailable(&driver));
+        }
+        try_join_all(ids.into_iter().map(|id| set_policy_boost(id, v))).await?;
+        Ok(())
+    }
+}
+
 pub async fn cpuinfo_max_freq(id: u64) -> Result<u64> {
     sysfs::read_u64(&path::cpuinfo_max_freq(id)).await
 }
@@ -31,6 +177,14 @@ pub async fn cpuinfo_min_freq(id: u64) -> Result<u64> {
     sysfs::read_u64(&path::cpuinfo_min_freq(id)).await
 }
 
+pub async fn scaling_available_frequencies(id: u64) -> Result<Vec<u64>> {
+    sysfs::read_u64_list(&path::scaling_available_frequencies(id), ' ').await
+}
+
+pub async fn scaling_boost_frequencies(id: u64) -> Result<Vec<u64>> {
+    sysfs::read_u64_list(&path::scaling_boost_frequencies(id), ' ').await
+}
+
 pub async fn scaling_cur_freq(id: u64) -> Result<u64> {
     sysfs::read_u64(&path::scaling_cur_freq(id)).await
 }
@@ -55,6 +209,10 @@ pub async fn scaling_min_freq(id: u64) -> Result<u64> {
     sysfs::read_u64(&path::scaling_min_freq(id)).await
 }
 
+pub async fn scaling_setspeed(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::scaling_setspeed(id)).await
+}
+
 pub async fn set_scaling_governor(id: u64, v: &str) -> Result<()> {
     sysfs::write_string(&path::scaling_governor(id), v).await
 }
@@ -66,3 +224,7 @@ pub async fn set_scaling_max_freq(id: u64, v: u64) -> Result<()> {
 pub async fn set_scaling_min_freq(id: u64, v: u64) -> Result<()> {
     sysfs::write_u64(&path::scaling_min_freq(id), v).await
 }
+
+pub async fn set_scaling_setspeed(id: u64, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::scaling_setspeed(id), v).await
+}
@@ -1,17 +1,95 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub(crate) fn root() -> PathBuf {
     PathBuf::from("/sys/devices/system/cpu/cpufreq")
 }
 
 pub(crate) fn policy(id: u64) -> PathBuf {
-    root().join(&format!("policy{}", id))
+    root().join(format!("policy{}", id))
 }
 
 pub(crate) fn policy_attr(i: u64, a: &str) -> PathBuf {
     policy(i).join(a)
 }
 
+pub(crate) fn cpu_policy(cpu: u64) -> PathBuf {
+    crate::cpu::path::cpu_attr(cpu, "cpufreq")
+}
+
+pub(crate) fn affected_cpus(id: u64) -> PathBuf {
+    policy_attr(id, "affected_cpus")
+}
+
+pub(crate) fn related_cpus(id: u64) -> PathBuf {
+    policy_attr(id, "related_cpus")
+}
+
+pub(crate) fn bios_limit(id: u64) -> PathBuf {
+    policy_attr(id, "bios_limit")
+}
+
+pub(crate) fn cpuinfo_transition_latency(id: u64) -> PathBuf {
+    policy_attr(id, "cpuinfo_transition_latency")
+}
+
+pub(crate) fn boost() -> PathBuf {
+    root().join("boost")
+}
+
+pub(crate) fn policy_boost(id: u64) -> PathBuf {
+    policy_attr(id, "boost")
+}
+
+pub(crate) fn governor(name: &str) -> PathBuf {
+    root().join(name)
+}
+
+pub(crate) fn policy_governor(id: u64, name: &str) -> PathBuf {
+    policy_attr(id, name)
+}
+
+// Tunables are relative to the governor directory, which is either per-policy
+// or global.
+pub(crate) fn governor_attr(dir: &Path, a: &str) -> PathBuf {
+    dir.join(a)
+}
+
+pub(crate) fn down_threshold(dir: &Path) -> PathBuf {
+    governor_attr(dir, "down_threshold")
+}
+
+pub(crate) fn freq_step(dir: &Path) -> PathBuf {
+    governor_attr(dir, "freq_step")
+}
+
+pub(crate) fn ignore_nice_load(dir: &Path) -> PathBuf {
+    governor_attr(dir, "ignore_nice_load")
+}
+
+pub(crate) fn io_is_busy(dir: &Path) -> PathBuf {
+    governor_attr(dir, "io_is_busy")
+}
+
+pub(crate) fn powersave_bias(dir: &Path) -> PathBuf {
+    governor_attr(dir, "powersave_bias")
+}
+
+pub(crate) fn rate_limit_us(dir: &Path) -> PathBuf {
+    governor_attr(dir, "rate_limit_us")
+}
+
+pub(crate) fn sampling_down_factor(dir: &Path) -> PathBuf {
+    governor_attr(dir, "sampling_down_factor")
+}
+
+pub(crate) fn sampling_rate(dir: &Path) -> PathBuf {
+    governor_attr(dir, "sampling_rate")
+}
+
+pub(crate) fn up_threshold(dir: &Path) -> PathBuf {
+    governor_attr(dir, "up_threshold")
+}
+
 pub(crate) fn cpuinfo_max_freq(id: u64) -> PathBuf {
     policy_attr(id, "cpuinfo_max_freq")
 }
@@ -20,6 +98,14 @@ pub(crate) fn cpuinfo_min_freq(id: u64) -> PathBuf {
     policy_attr(id, "cpuinfo_min_freq")
 }
 
+pub(crate) fn scaling_available_frequencies(id: u64) -> PathBuf {
+    policy_attr(id, "scaling_available_frequencies")
+}
+
+pub(crate) fn scaling_boost_frequencies(id: u64) -> PathBuf {
+    policy_attr(id, "scaling_boost_frequencies")
+}
+
 pub(crate) fn scaling_cur_freq(id: u64) -> PathBuf {
     policy_attr(id, "scaling_cur_freq")
 }
@@ -43,3 +129,31 @@ pub(crate) fn scaling_max_freq(id: u64) -> PathBuf {
 pub(crate) fn scaling_min_freq(id: u64) -> PathBuf {
     policy_attr(id, "scaling_min_freq")
 }
+
+pub(crate) fn scaling_setspeed(id: u64) -> PathBuf {
+    policy_attr(id, "scaling_setspeed")
+}
+
+pub(crate) fn stats(id: u64) -> PathBuf {
+    policy_attr(id, "stats")
+}
+
+pub(crate) fn stats_attr(id: u64, a: &str) -> PathBuf {
+    stats(id).join(a)
+}
+
+pub(crate) fn time_in_state(id: u64) -> PathBuf {
+    stats_attr(id, "time_in_state")
+}
+
+pub(crate) fn total_trans(id: u64) -> PathBuf {
+    stats_attr(id, "total_trans")
+}
+
+pub(crate) fn trans_table(id: u64) -> PathBuf {
+    stats_attr(id, "trans_table")
+}
+
+pub(crate) fn reset(id: u64) -> PathBuf {
+    stats_attr(id, "reset")
+}
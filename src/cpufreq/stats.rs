@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
+use tokio::time::MissedTickBehavior;
+
+use crate::cpufreq::{self, path};
+use crate::util::sysfs;
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct TransTable {
+    pub freqs: Vec<u64>,
+    pub transitions: Vec<Vec<u64>>,
+}
+
+impl TransTable {
+    fn parse(path: &Path, s: &str) -> Result<Self> {
+        let parse_row = |line: &str| -> Result<Vec<u64>> {
+            line.split_whitespace()
+                .map(|v| {
+                    v.parse::<u64>()
+                        .map_err(|_| Error::sysfs_parse(path, "trans_table", line))
+                })
+                .collect()
+        };
+        // After the `From : To` header, the first row lists the frequencies
+        // and each following row is one source frequency, in the same order.
+        let mut r = Self::default();
+        for line in s.lines().skip(1) {
+            match line.split_once(':') {
+                Some((from, row)) if from.trim().is_empty() => r.freqs = parse_row(row)?,
+                Some((from, row)) => {
+                    let row = parse_row(row)?;
+                    let from = from.trim().parse::<u64>().ok();
+                    if from != r.freqs.get(r.transitions.len()).copied()
+                        || row.len() != r.freqs.len()
+                    {
+                        return Err(Error::sysfs_parse(path, "trans_table", line));
+                    }
+                    r.transitions.push(row);
+                },
+                None => return Err(Error::sysfs_parse(path, "trans_table", line)),
+            }
+        }
+        Ok(r)
+    }
+
+    pub fn get(&self, from: u64, to: u64) -> Option<u64> {
+        let from = self.freqs.iter().position(|f| *f == from)?;
+        let to = self.freqs.iter().position(|f| *f == to)?;
+        self.transitions.get(from)?.get(to).copied()
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Stats {
+    pub time_in_state: BTreeMap<u64, u64>,
+    pub total_trans: u64,
+}
+
+impl Stats {
+    pub fn residency_since(&self, prev: &Stats) -> Residency {
+        let time: BTreeMap<_, _> = self
+            .time_in_state
+            .iter()
+            .map(|(f, t)| {
+                let prev = prev.time_in_state.get(f).copied().unwrap_or(0);
+                (*f, t.saturating_sub(prev))
+            })
+            .collect();
+        let total: u64 = time.values().sum();
+        let percent = time
+            .iter()
+            .map(|(f, t)| {
+                let pct = if total == 0 {
+                    0.
+                } else {
+                    *t as f64 / total as f64 * 100.
+                };
+                (*f, pct)
+            })
+            .collect();
+        let transitions = self.total_trans.saturating_sub(prev.total_trans);
+        Residency {
+            time,
+            percent,
+            transitions,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Residency {
+    pub time: BTreeMap<u64, u64>,
+    pub percent: BTreeMap<u64, f64>,
+    pub transitions: u64,
+}
+
+pub async fn available() -> Result<bool> {
+    Ok(path::stats(0).is_dir())
+}
+
+pub async fn exists(id: u64) -> Result<bool> {
+    Ok(path::stats(id).is_dir())
+}
+
+pub fn ids() -> impl Stream<Item = Result<u64>> {
+    cpufreq::ids().try_filter(|id| futures::future::ready(path::stats(*id).is_dir()))
+}
+
+fn parse_time_in_state(path: &Path, s: &str) -> Result<BTreeMap<u64, u64>> {
+    s.lines()
+        .map(|line| {
+            let p: Vec<_> = line.split_whitespace().collect();
+            match &p[..] {
+                [freq, time] => match (freq.parse::<u64>(), time.parse::<u64>()) {
+                    (Ok(freq), Ok(time)) => Ok((freq, time)),
+                    _ => Err(Error::sysfs_parse(path, "time_in_state", line)),
+                },
+                _ => Err(Error::sysfs_parse(path, "time_in_state", line)),
+            }
+        })
+        .collect()
+}
+
+pub async fn time_in_state(id: u64) -> Result<BTreeMap<u64, u64>> {
+    let path = path::time_in_state(id);
+    let s = sysfs::read_string(&path).await?;
+    parse_time_in_state(&path, &s)
+}
+
+pub async fn total_trans(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::total_trans(id)).await
+}
+
+pub async fn trans_table(id: u64) -> Result<TransTable> {
+    let path = path::trans_table(id);
+    let s = sysfs::read_string(&path).await?;
+    TransTable::parse(&path, &s)
+}
+
+pub async fn stats(id: u64) -> Result<Stats> {
+    let (time_in_state, total_trans) = tokio::try_join!(time_in_state(id), total_trans(id))?;
+    Ok(Stats {
+        time_in_state,
+        total_trans,
+    })
+}
+
+pub async fn reset(id: u64) -> Result<()> {
+    sysfs::write_bool(&path::reset(id), true).await
+}
+
+async fn all_stats() -> Result<BTreeMap<u64, Stats>> {
+    ids()
+        .and_then(|id| async move { Ok((id, stats(id).await?)) })
+        .try_collect()
+        .await
+}
+
+pub fn sampler(interval: Duration) -> impl Stream<Item = Result<BTreeMap<u64, Residency>>> {
+    try_stream! {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval.tick().await;
+        let mut prev = all_stats().await?;
+        loop {
+            interval.tick().await;
+            let cur = all_stats().await?;
+            let r = cur
+                .iter()
+                .filter_map(|(id, s)| prev.get(id).map(|p| (*id, s.residency_since(p))))
+                .collect();
+            yield r;
+            prev = cur;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANS_TABLE: &str = "   From  :    To
+         :   3400000   2800000   2200000
+  3400000:         0        12         3
+  2800000:        10         0         7
+  2200000:         5         9         0
+";
+
+    #[test]
+    fn trans_table() {
+        let r = TransTable::parse(Path::new("trans_table"), TRANS_TABLE).unwrap();
+        assert_eq!(r.freqs, vec![3_400_000, 2_800_000, 2_200_000]);
+        assert_eq!(
+            r.transitions,
+            vec![vec![0, 12, 3], vec![10, 0, 7], vec![5, 9, 0]]
+        );
+        assert_eq!(r.get(3_400_000, 2_800_000), Some(12));
+        assert_eq!(r.get(2_800_000, 3_400_000), Some(10));
+        assert_eq!(r.get(2_200_000, 2_800_000), Some(9));
+        assert_eq!(r.get(2_200_000, 1_000_000), None);
+    }
+
+    #[test]
+    fn trans_table_invalid() {
+        let path = Path::new("trans_table");
+        let short_row = "   From  :    To\n         :   3400000   2800000\n  3400000:         0\n";
+        assert!(TransTable::parse(path, short_row).is_err());
+        let wrong_from = "   From  :    To\n         :   3400000   2800000\n  2800000:  0  1\n";
+        assert!(TransTable::parse(path, wrong_from).is_err());
+        let no_colon = "   From  :    To\n         :   3400000\n  3400000  0\n";
+        assert!(TransTable::parse(path, no_colon).is_err());
+    }
+
+    #[test]
+    fn time_in_state() {
+        let path = Path::new("time_in_state");
+        let r = parse_time_in_state(path, "3400000 120\n2800000 4500\n2200000 0\n").unwrap();
+        assert_eq!(
+            r.into_iter().collect::<Vec<_>>(),
+            vec![(2_200_000, 0), (2_800_000, 4_500), (3_400_000, 120)]
+        );
+        assert!(parse_time_in_state(path, "3400000\n").is_err());
+        assert!(parse_time_in_state(path, "3400000 x\n").is_err());
+    }
+
+    #[test]
+    fn residency_since() {
+        let prev = Stats {
+            time_in_state: [(2_200_000, 100), (3_400_000, 50)].into(),
+            total_trans: 4,
+        };
+        let cur = Stats {
+            time_in_state: [(2_200_000, 130), (3_400_000, 140)].into(),
+            total_trans: 9,
+        };
+        let r = cur.residency_since(&prev);
+        assert_eq!(r.time, [(2_200_000, 30), (3_400_000, 90)].into());
+        assert_eq!(r.percent, [(2_200_000, 25.), (3_400_000, 75.)].into());
+        assert_eq!(r.transitions, 5);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::cpufreq::{self, path};
+use crate::Result;
+
+pub const USERSPACE: &str = "userspace";
+
+// Holds a policy on the `userspace` governor at a fixed frequency. The previous
+// governor is restored by `release()`, or on drop if `release()` was never
+// called or failed.
+#[derive(Debug)]
+pub struct Pin {
+    id: u64,
+    governor: Option<String>,
+}
+
+impl Pin {
+    pub async fn new(id: u64, freq: u64) -> Result<Self> {
+        let governor = cpufreq::scaling_governor(id).await?;
+        if governor != USERSPACE {
+            cpufreq::set_scaling_governor(id, USERSPACE).await?;
+        }
+        let governor = Some(governor);
+        let r = Self { id, governor };
+        r.set_freq(freq).await?;
+        Ok(r)
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn governor(&self) -> Option<&str> {
+        self.governor.as_deref()
+    }
+
+    pub async fn freq(&self) -> Result<u64> {
+        cpufreq::scaling_setspeed(self.id).await
+    }
+
+    pub async fn set_freq(&self, v: u64) -> Result<()> {
+        cpufreq::set_scaling_setspeed(self.id, v).await
+    }
+
+    pub async fn release(mut self) -> Result<()> {
+        if let Some(governor) = self.governor.take() {
+            let r = cpufreq::set_scaling_governor(self.id, &governor).await;
+            if r.is_err() {
+                self.governor.replace(governor);
+            }
+            r
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Drop for Pin {
+    fn drop(&mut self) {
+        if let Some(governor) = self.governor.take() {
+            let path = path::scaling_governor(self.id);
+            let _r = std::fs::write(&path, &governor);
+            #[cfg(feature = "logging")]
+            match _r {
+                Ok(()) => log::trace!("OK sysfs w {} {}", path.display(), governor),
+                Err(e) => log::error!("ERR sysfs w {} {} {}", path.display(), governor, e),
+            }
+        }
+    }
+}
@@ -1,7 +1,12 @@
+use std::collections::BTreeMap;
+
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
+use crate::cpu::CoreType;
 use crate::cpufreq;
+use crate::cpufreq::governor::Tunables;
+use crate::cpufreq::stats::{self, TransTable};
 #[cfg(feature = "cache")]
 use crate::cpufreq::Cache;
 use crate::Result;
@@ -24,6 +29,26 @@ impl Values {
         cpufreq::ids()
     }
 
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        cpufreq::ids_for_core_type(core_type)
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        cpufreq::housekeeping_ids()
+    }
+
+    pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        cpufreq::non_housekeeping_ids()
+    }
+
+    pub fn id_for_cpu(cpu: u64) -> impl Future<Output = Result<Option<u64>>> {
+        cpufreq::id_for_cpu(cpu)
+    }
+
+    pub async fn for_cpu(cpu: u64) -> Result<Option<Self>> {
+        Ok(cpufreq::id_for_cpu(cpu).await?.map(Self::new))
+    }
+
     pub fn all() -> impl Stream<Item = Result<Self>> {
         cpufreq::ids().map_ok(Self::new)
     }
@@ -36,6 +61,22 @@ impl Values {
         self.id
     }
 
+    pub fn affected_cpus(&self) -> impl Future<Output = Result<Vec<u64>>> {
+        cpufreq::affected_cpus(self.id)
+    }
+
+    pub fn related_cpus(&self) -> impl Future<Output = Result<Vec<u64>>> {
+        cpufreq::related_cpus(self.id)
+    }
+
+    pub fn bios_limit(&self) -> impl Future<Output = Result<u64>> {
+        cpufreq::bios_limit(self.id)
+    }
+
+    pub fn boost(&self) -> impl Future<Output = Result<bool>> {
+        cpufreq::policy_boost(self.id)
+    }
+
     pub fn cpuinfo_max_freq(&self) -> impl Future<Output = Result<u64>> {
         cpufreq::cpuinfo_max_freq(self.id)
     }
@@ -44,6 +85,18 @@ impl Values {
         cpufreq::cpuinfo_min_freq(self.id)
     }
 
+    pub fn cpuinfo_transition_latency(&self) -> impl Future<Output = Result<u64>> {
+        cpufreq::cpuinfo_transition_latency(self.id)
+    }
+
+    pub fn scaling_available_frequencies(&self) -> impl Future<Output = Result<Vec<u64>>> {
+        cpufreq::scaling_available_frequencies(self.id)
+    }
+
+    pub fn scaling_boost_frequencies(&self) -> impl Future<Output = Result<Vec<u64>>> {
+        cpufreq::scaling_boost_frequencies(self.id)
+    }
+
     pub fn scaling_cur_freq(&self) -> impl Future<Output = Result<u64>> {
         cpufreq::scaling_cur_freq(self.id)
     }
@@ -68,6 +121,34 @@ impl Values {
         cpufreq::scaling_min_freq(self.id)
     }
 
+    pub fn scaling_setspeed(&self) -> impl Future<Output = Result<u64>> {
+        cpufreq::scaling_setspeed(self.id)
+    }
+
+    pub fn governor_tunables(&self) -> impl Future<Output = Result<Option<Tunables>>> {
+        Tunables::for_policy(self.id)
+    }
+
+    pub fn time_in_state(&self) -> impl Future<Output = Result<BTreeMap<u64, u64>>> {
+        stats::time_in_state(self.id)
+    }
+
+    pub fn total_trans(&self) -> impl Future<Output = Result<u64>> {
+        stats::total_trans(self.id)
+    }
+
+    pub fn trans_table(&self) -> impl Future<Output = Result<TransTable>> {
+        stats::trans_table(self.id)
+    }
+
+    pub fn reset_stats(&self) -> impl Future<Output = Result<()>> {
+        stats::reset(self.id)
+    }
+
+    pub fn set_boost(&self, v: bool) -> impl Future<Output = Result<()>> {
+        cpufreq::set_policy_boost(self.id, v)
+    }
+
     pub async fn set_scaling_governor(&self, v: impl AsRef<str>) -> Result<()> {
         cpufreq::set_scaling_governor(self.id, v.as_ref()).await
     }
@@ -79,6 +160,10 @@ impl Values {
     pub fn set_scaling_min_freq(&self, v: u64) -> impl Future<Output = Result<()>> {
         cpufreq::set_scaling_min_freq(self.id, v)
     }
+
+    pub fn set_scaling_setspeed(&self, v: u64) -> impl Future<Output = Result<()>> {
+        cpufreq::set_scaling_setspeed(self.id, v)
+    }
 }
 
 #[cfg(feature = "cache")]
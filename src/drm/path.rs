@@ -7,7 +7,7 @@ pub(crate) fn root() -> PathBuf {
 }
 
 pub(crate) fn card(id: u64) -> PathBuf {
-    root().join(&format!("card{}", id))
+    root().join(format!("card{}", id))
 }
 
 pub(crate) fn card_attr(id: u64, a: &str) -> PathBuf {
@@ -0,0 +1,223 @@
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::process::ExitStatus;
+use std::time::Duration;
+
+use futures::future::try_join_all;
+use futures::stream::TryStreamExt as _;
+use tokio::process::Command;
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::intel_rapl::zone::meter::{self, Counter};
+use crate::powercap::zone::Id;
+use crate::{intel_rapl, Error, Result};
+#[cfg(feature = "nvml")]
+use crate::{nvml, NvmlError};
+
+// Counters are polled at this interval while a scope runs a future, so rapl
+// wraparound is not missed during long runs.
+const POLL: Duration = Duration::from_secs(1);
+
+// Zone values are from rapl `energy_uj`, gpu values are from nvml's total
+// energy counter, keyed by drm card id. All values are in joules.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Energy {
+    pub elapsed: Duration,
+    pub zones: BTreeMap<Id, f64>,
+    pub gpus: BTreeMap<u64, f64>,
+}
+
+impl Energy {
+    pub fn zone_joules(&self) -> f64 {
+        self.zones.values().sum()
+    }
+
+    pub fn gpu_joules(&self) -> f64 {
+        self.gpus.values().sum()
+    }
+
+    pub fn total_joules(&self) -> f64 {
+        self.zone_joules() + self.gpu_joules()
+    }
+
+    pub fn avg_watts(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs > 0. {
+            self.total_joules() / secs
+        } else {
+            0.
+        }
+    }
+}
+
+// Gpus older than Volta have no total energy counter, so they are left out
+// instead of failing the scope.
+#[cfg(feature = "nvml")]
+async fn gpu_ids() -> Result<Vec<u64>> {
+    if !nvml::available().await? {
+        return Ok(vec![]);
+    }
+    let ids: Vec<u64> = nvml::ids().try_collect().await?;
+    let ids = try_join_all(ids.into_iter().map(|id| async move {
+        match nvml::total_energy(id).await {
+            Ok(_) => Ok(Some(id)),
+            Err(Error::NvmlIo {
+                source: NvmlError::NotSupported,
+                ..
+            }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }))
+    .await?;
+    Ok(ids.into_iter().flatten().collect())
+}
+
+#[cfg(not(feature = "nvml"))]
+async fn gpu_ids() -> Result<Vec<u64>> {
+    Ok(vec![])
+}
+
+#[cfg(feature = "nvml")]
+async fn gpu_energy(ids: impl Iterator<Item = u64>) -> Result<BTreeMap<u64, u64>> {
+    let r = try_join_all(ids.map(|id| async move { Ok((id, nvml::total_energy(id).await?)) }))
+        .await?
+        .into_iter()
+        .collect();
+    Ok(r)
+}
+
+#[cfg(not(feature = "nvml"))]
+async fn gpu_energy(ids: impl Iterator<Item = u64>) -> Result<BTreeMap<u64, u64>> {
+    match ids.collect::<Vec<_>>().as_slice() {
+        [] => Ok(BTreeMap::new()),
+        _ => Err(Error::non_sequitor("Gpu energy requires the nvml feature")),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EnergyScope {
+    start: Instant,
+    zones: Vec<Counter>,
+    gpus: BTreeMap<u64, u64>,
+}
+
+impl EnergyScope {
+    // Records all rapl packages and nvml gpus. Subzones and psys are excluded
+    // since their energy overlaps with that of the packages.
+    pub async fn start() -> Result<Self> {
+        let zones: Vec<_> = if intel_rapl::available().await? {
+            meter::package_ids().try_collect().await?
+        } else {
+            vec![]
+        };
+        let gpus = gpu_ids().await?;
+        Self::start_with(zones, gpus).await
+    }
+
+    pub async fn start_with<Z, T, G>(zones: Z, gpus: G) -> Result<Self>
+    where
+        Z: IntoIterator<Item = T>,
+        T: Into<Id>,
+        G: IntoIterator<Item = u64>,
+    {
+        let (zones, gpus) = tokio::try_join!(
+            try_join_all(zones.into_iter().map(|v| Counter::new(v.into()))),
+            gpu_energy(gpus.into_iter()),
+        )?;
+        Ok(Self {
+            start: Instant::now(),
+            zones,
+            gpus,
+        })
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    pub async fn update(&mut self) -> Result<()> {
+        try_join_all(self.zones.iter_mut().map(|c| c.update())).await?;
+        Ok(())
+    }
+
+    pub async fn stop(mut self) -> Result<Energy> {
+        let ids: Vec<_> = self.gpus.keys().copied().collect();
+        let (_, gpus) = tokio::try_join!(self.update(), gpu_energy(ids.into_iter()))?;
+        let elapsed = self.start.elapsed();
+        let zones = self
+            .zones
+            .iter()
+            .map(|c| (c.id.clone(), c.total_uj as f64 / 1_000_000.))
+            .collect();
+        let gpus = gpus
+            .into_iter()
+            .map(|(id, v)| {
+                let start = self.gpus.get(&id).copied().unwrap_or(v);
+                (id, v.saturating_sub(start) as f64 / 1_000.)
+            })
+            .collect();
+        Ok(Energy {
+            elapsed,
+            zones,
+            gpus,
+        })
+    }
+
+    pub async fn run<F>(mut self, f: F) -> Result<(F::Output, Energy)>
+    where
+        F: Future,
+    {
+        let mut interval = tokio::time::interval(POLL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        interval.tick().await;
+        tokio::pin!(f);
+        let r = loop {
+            tokio::select! {
+                r = &mut f => break r,
+                _ = interval.tick() => self.update().await?,
+            }
+        };
+        let energy = self.stop().await?;
+        Ok((r, energy))
+    }
+
+    // The closure runs on the current task, so counters are only read before
+    // and after it returns.
+    pub async fn run_fn<F, T>(self, f: F) -> Result<(T, Energy)>
+    where
+        F: FnOnce() -> T,
+    {
+        self.run(async move { f() }).await
+    }
+
+    pub async fn run_command(self, cmd: &mut Command) -> Result<(ExitStatus, Energy)> {
+        let program = cmd.as_std().get_program().to_string_lossy().into_owned();
+        // Don't leave the child running when the counters fail or the future
+        // is dropped.
+        let mut child = cmd
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| Error::process(e, &program))?;
+        match self.run(child.wait()).await {
+            Ok((r, energy)) => {
+                let r = r.map_err(|e| Error::process(e, &program))?;
+                Ok((r, energy))
+            },
+            Err(e) => {
+                let _ = child.kill().await;
+                Err(e)
+            },
+        }
+    }
+}
+
+pub async fn measure<F>(f: F) -> Result<(F::Output, Energy)>
+where
+    F: Future,
+{
+    EnergyScope::start().await?.run(f).await
+}
+
+pub async fn measure_command(cmd: &mut Command) -> Result<(ExitStatus, Energy)> {
+    EnergyScope::start().await?.run_command(cmd).await
+}
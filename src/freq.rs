@@ -0,0 +1,233 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
+use tokio::time::MissedTickBehavior;
+
+use crate::{cpufreq, i915, xe, Result};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: u64,
+    pub max: u64,
+    pub avg: f64,
+    pub p50: u64,
+    pub p90: u64,
+    pub p99: u64,
+}
+
+impl Stats {
+    pub fn new<'a>(values: impl IntoIterator<Item = &'a u64>) -> Option<Self> {
+        let mut v: Vec<_> = values.into_iter().copied().collect();
+        if v.is_empty() {
+            return None;
+        }
+        v.sort_unstable();
+        let pct = |p: usize| v[(v.len() * p).div_ceil(100).clamp(1, v.len()) - 1];
+        let r = Self {
+            samples: v.len(),
+            min: v[0],
+            max: v[v.len() - 1],
+            avg: v.iter().sum::<u64>() as f64 / v.len() as f64,
+            p50: pct(50),
+            p90: pct(90),
+            p99: pct(99),
+        };
+        Some(r)
+    }
+}
+
+// Cpu values are `scaling_cur_freq` in kHz, keyed by cpufreq policy id. Gpu
+// values are actual frequencies in MHz, keyed by drm card id.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Summary {
+    pub policies: BTreeMap<u64, Stats>,
+    pub cpu: Option<Stats>,
+    pub cpu_window: Option<Stats>,
+    pub gpus: BTreeMap<u64, Stats>,
+    pub gpu: Option<Stats>,
+    pub gpu_window: Option<Stats>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Window {
+    size: usize,
+    samples: BTreeMap<u64, VecDeque<u64>>,
+    latest: BTreeMap<u64, u64>,
+}
+
+impl Window {
+    fn new(size: usize) -> Self {
+        let size = size.max(1);
+        Self {
+            size,
+            ..Default::default()
+        }
+    }
+
+    fn push(&mut self, latest: BTreeMap<u64, u64>) {
+        self.samples.retain(|id, _| latest.contains_key(id));
+        for (id, v) in &latest {
+            let s = self.samples.entry(*id).or_default();
+            if s.len() == self.size {
+                s.pop_front();
+            }
+            s.push_back(*v);
+        }
+        self.latest = latest;
+    }
+
+    fn stats(&self) -> BTreeMap<u64, Stats> {
+        self.samples
+            .iter()
+            .filter_map(|(id, s)| Stats::new(s).map(|s| (*id, s)))
+            .collect()
+    }
+
+    fn latest(&self) -> Option<Stats> {
+        Stats::new(self.latest.values())
+    }
+
+    fn all(&self) -> Option<Stats> {
+        Stats::new(self.samples.values().flatten())
+    }
+}
+
+async fn cpu_freqs() -> Result<BTreeMap<u64, u64>> {
+    if !cpufreq::available().await? {
+        return Ok(BTreeMap::new());
+    }
+    cpufreq::ids()
+        .and_then(|id| async move { Ok((id, cpufreq::scaling_cur_freq(id).await?)) })
+        .try_collect()
+        .await
+}
+
+async fn gpu_freqs() -> Result<BTreeMap<u64, u64>> {
+    let mut r = BTreeMap::new();
+    if i915::available().await? {
+        let i915: Vec<_> = i915::ids()
+            .and_then(|id| async move { Ok((id, i915::act_freq_mhz(id).await?)) })
+            .try_collect()
+            .await?;
+        r.extend(i915);
+    }
+    // Xe cards report the frequency of their first gt, as i915 does.
+    if xe::available().await? {
+        let xe: Vec<_> = xe::ids()
+            .and_then(|id| async move { Ok((id, xe::act_freq_mhz(id).await?)) })
+            .try_collect()
+            .await?;
+        r.extend(xe);
+    }
+    #[cfg(feature = "nvml")]
+    if crate::nvml::available().await? {
+        let nvml: Vec<_> = crate::nvml::ids()
+            .and_then(|id| async move { Ok((id, crate::nvml::gfx_freq(id).await? as u64)) })
+            .try_collect()
+            .await?;
+        r.extend(nvml);
+    }
+    Ok(r)
+}
+
+pub async fn summary() -> Result<Summary> {
+    let (cpu, gpu) = tokio::try_join!(cpu_freqs(), gpu_freqs())?;
+    let (mut cpus, mut gpus) = (Window::new(1), Window::new(1));
+    cpus.push(cpu);
+    gpus.push(gpu);
+    Ok(summarize(&cpus, &gpus))
+}
+
+fn summarize(cpus: &Window, gpus: &Window) -> Summary {
+    Summary {
+        policies: cpus.stats(),
+        cpu: cpus.latest(),
+        cpu_window: cpus.all(),
+        gpus: gpus.stats(),
+        gpu: gpus.latest(),
+        gpu_window: gpus.all(),
+    }
+}
+
+pub fn sampler(interval: Duration, window: usize) -> impl Stream<Item = Result<Summary>> {
+    try_stream! {
+        let mut interval = tokio::time::interval(interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let (mut cpus, mut gpus) = (Window::new(window), Window::new(window));
+        loop {
+            interval.tick().await;
+            let (cpu, gpu) = tokio::try_join!(cpu_freqs(), gpu_freqs())?;
+            cpus.push(cpu);
+            gpus.push(gpu);
+            yield summarize(&cpus, &gpus);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_empty() {
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn stats_one() {
+        let r = Stats::new(&[1200]).unwrap();
+        assert_eq!(
+            r,
+            Stats {
+                samples: 1,
+                min: 1200,
+                max: 1200,
+                avg: 1200.,
+                p50: 1200,
+                p90: 1200,
+                p99: 1200,
+            }
+        );
+    }
+
+    #[test]
+    fn stats_two() {
+        let r = Stats::new(&[2000, 1000]).unwrap();
+        assert_eq!((r.samples, r.min, r.max, r.avg), (2, 1000, 2000, 1500.));
+        assert_eq!((r.p50, r.p90, r.p99), (1000, 2000, 2000));
+    }
+
+    #[test]
+    fn stats_hundred() {
+        let v: Vec<u64> = (1..=100).rev().collect();
+        let r = Stats::new(&v).unwrap();
+        assert_eq!((r.samples, r.min, r.max, r.avg), (100, 1, 100, 50.5));
+        assert_eq!((r.p50, r.p90, r.p99), (50, 90, 99));
+    }
+
+    #[test]
+    fn window_push() {
+        let mut w = Window::new(2);
+        w.push([(0, 100), (1, 200)].into());
+        w.push([(0, 110), (1, 210)].into());
+        w.push([(0, 120), (1, 220)].into());
+        assert_eq!(w.samples[&0], [110, 120]);
+        assert_eq!(w.samples[&1], [210, 220]);
+        // Ids missing from the latest sample are dropped.
+        w.push([(0, 130)].into());
+        assert_eq!(w.samples[&0], [120, 130]);
+        assert!(!w.samples.contains_key(&1));
+        assert_eq!(w.latest, [(0, 130)].into());
+    }
+
+    #[test]
+    fn window_size() {
+        let mut w = Window::new(0);
+        w.push([(0, 100)].into());
+        w.push([(0, 110)].into());
+        assert_eq!(w.samples[&0], [110]);
+    }
+}
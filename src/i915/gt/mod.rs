@@ -0,0 +1,151 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub(crate) mod path;
+mod values;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
+
+pub use crate::i915::available;
+#[cfg(feature = "cache")]
+pub use crate::i915::gt::cache::Cache;
+pub use crate::i915::gt::values::Values;
+use crate::util::sysfs;
+use crate::{i915, Result};
+
+pub const STATUS: &str = "status";
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id {
+    card: u64,
+    gt: u64,
+}
+
+impl Id {
+    pub fn new(card: u64, gt: u64) -> Self {
+        Self { card, gt }
+    }
+
+    pub fn card(&self) -> u64 {
+        self.card
+    }
+
+    pub fn gt(&self) -> u64 {
+        self.gt
+    }
+}
+
+impl From<(u64, u64)> for Id {
+    fn from(v: (u64, u64)) -> Self {
+        Self::new(v.0, v.1)
+    }
+}
+
+impl From<Id> for (u64, u64) {
+    fn from(v: Id) -> Self {
+        (v.card, v.gt)
+    }
+}
+
+pub fn ids() -> impl Stream<Item = Result<Id>> {
+    i915::ids().map_ok(ids_for_card).try_flatten()
+}
+
+pub fn ids_for_card(card: u64) -> impl Stream<Item = Result<Id>> {
+    try_stream! {
+        let root = path::root(card);
+        if root.is_dir() {
+            let mut gts: Vec<_> = sysfs::read_ids(&root, "gt").try_collect().await?;
+            gts.sort_unstable();
+            for gt in gts {
+                yield Id::new(card, gt);
+            }
+        } else if i915::path::act_freq_mhz(card).is_file() {
+            yield Id::new(card, 0);
+        }
+    }
+}
+
+pub async fn exists(id: impl Into<Id>) -> Result<bool> {
+    Ok(path::act_freq_mhz(id.into()).is_file())
+}
+
+pub async fn act_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::act_freq_mhz(id.into())).await
+}
+
+pub async fn boost_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::boost_freq_mhz(id.into())).await
+}
+
+pub async fn cur_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::cur_freq_mhz(id.into())).await
+}
+
+pub async fn max_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::max_freq_mhz(id.into())).await
+}
+
+pub async fn min_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::min_freq_mhz(id.into())).await
+}
+
+pub async fn rp0_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::rp0_freq_mhz(id.into())).await
+}
+
+pub async fn rp1_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::rp1_freq_mhz(id.into())).await
+}
+
+pub async fn rpn_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::rpn_freq_mhz(id.into())).await
+}
+
+pub async fn rc6_enable(id: impl Into<Id>) -> Result<bool> {
+    sysfs::read_bool(&path::rc6_enable(id.into())).await
+}
+
+pub async fn rc6_residency_ms(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::rc6_residency_ms(id.into())).await
+}
+
+pub async fn throttle_reason(id: impl Into<Id>, reason: &str) -> Result<bool> {
+    sysfs::read_bool(&path::throttle_reason(id.into(), reason)).await
+}
+
+// Names of the asserted `throttle_reason_*` files, excluding the `status`
+// summary. Empty when the kernel has no per-gt directories.
+pub async fn throttle_reasons(id: impl Into<Id>) -> Result<Vec<String>> {
+    let id = id.into();
+    let mut r = vec![];
+    if !path::gt(id).is_dir() {
+        return Ok(r);
+    }
+    let mut names: Vec<_> = sysfs::read_names(&path::gt(id))
+        .try_filter_map(|v| async move {
+            let v = v.strip_prefix("throttle_reason_").map(String::from);
+            Ok(v.filter(|v| v != STATUS))
+        })
+        .try_collect()
+        .await?;
+    names.sort();
+    for v in names {
+        if throttle_reason(id, &v).await? {
+            r.push(v);
+        }
+    }
+    Ok(r)
+}
+
+pub async fn set_boost_freq_mhz(id: impl Into<Id>, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::boost_freq_mhz(id.into()), v).await
+}
+
+pub async fn set_max_freq_mhz(id: impl Into<Id>, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::max_freq_mhz(id.into()), v).await
+}
+
+pub async fn set_min_freq_mhz(id: impl Into<Id>, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::min_freq_mhz(id.into()), v).await
+}
@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+
+use crate::drm::path::card_attr;
+use crate::i915::gt::Id;
+use crate::i915::path as legacy;
+
+pub(crate) fn root(card: u64) -> PathBuf {
+    card_attr(card, "gt")
+}
+
+pub(crate) fn gt(id: Id) -> PathBuf {
+    root(id.card).join(format!("gt{}", id.gt))
+}
+
+pub(crate) fn gt_attr(id: Id, a: &str) -> PathBuf {
+    gt(id).join(a)
+}
+
+// Kernels without per-gt directories only have the card-level files, which
+// describe gt 0.
+fn gt_attr_or(id: Id, a: &str, legacy: impl FnOnce(u64) -> PathBuf) -> PathBuf {
+    let p = gt_attr(id, a);
+    if id.gt == 0 && !gt(id).is_dir() {
+        legacy(id.card)
+    } else {
+        p
+    }
+}
+
+pub(crate) fn act_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_act_freq_mhz", legacy::act_freq_mhz)
+}
+
+pub(crate) fn boost_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_boost_freq_mhz", legacy::boost_freq_mhz)
+}
+
+pub(crate) fn cur_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_cur_freq_mhz", legacy::cur_freq_mhz)
+}
+
+pub(crate) fn max_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_max_freq_mhz", legacy::max_freq_mhz)
+}
+
+pub(crate) fn min_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_min_freq_mhz", legacy::min_freq_mhz)
+}
+
+pub(crate) fn rp0_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_RP0_freq_mhz", legacy::rp0_freq_mhz)
+}
+
+pub(crate) fn rp1_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_RP1_freq_mhz", legacy::rp1_freq_mhz)
+}
+
+pub(crate) fn rpn_freq_mhz(id: Id) -> PathBuf {
+    gt_attr_or(id, "rps_RPn_freq_mhz", legacy::rpn_freq_mhz)
+}
+
+pub(crate) fn rc6_enable(id: Id) -> PathBuf {
+    gt_attr_or(id, "rc6_enable", |card| card_attr(card, "power/rc6_enable"))
+}
+
+pub(crate) fn rc6_residency_ms(id: Id) -> PathBuf {
+    gt_attr_or(id, "rc6_residency_ms", |card| {
+        card_attr(card, "power/rc6_residency_ms")
+    })
+}
+
+pub(crate) fn throttle_reason(id: Id, reason: &str) -> PathBuf {
+    gt_attr(id, &format!("throttle_reason_{}", reason))
+}
@@ -1,5 +1,6 @@
 #[cfg(feature = "cache")]
 mod cache;
+pub mod gt;
 pub(crate) mod path;
 mod values;
 
@@ -1,13 +1,15 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-use crate::intel_pstate::policy::{self, Values};
+use crate::cpu::CoreType;
+use crate::intel_pstate::policy::{self, path, Epb, Epp, Values};
 use crate::util::cell::Cell;
-use crate::Result;
+use crate::{Error, Result};
 
 #[derive(Clone, Debug)]
 pub struct Cache {
     id: u64,
+    base_frequency: Cell<u64>,
     energy_perf_bias: Cell<u64>,
     energy_performance_preference: Cell<String>,
     energy_performance_available_preferences: Cell<Vec<String>>,
@@ -26,6 +28,18 @@ impl Cache {
         policy::ids()
     }
 
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        policy::ids_for_core_type(core_type)
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::housekeeping_ids()
+    }
+
+    pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::non_housekeeping_ids()
+    }
+
     pub fn all() -> impl Stream<Item = Result<Self>> {
         policy::ids().map_ok(Self::new)
     }
@@ -33,6 +47,7 @@ impl Cache {
     pub fn new(id: u64) -> Self {
         Self {
             id,
+            base_frequency: Cell::default(),
             energy_perf_bias: Cell::default(),
             energy_performance_preference: Cell::default(),
             energy_performance_available_preferences: Cell::default(),
@@ -45,18 +60,48 @@ impl Cache {
 
     pub async fn clear(&self) {
         tokio::join!(
+            self.base_frequency.clear(),
             self.energy_perf_bias.clear(),
             self.energy_performance_preference.clear(),
             self.energy_performance_available_preferences.clear(),
         );
     }
 
+    pub async fn base_frequency(&self) -> Result<u64> {
+        self.base_frequency
+            .get_or_load(policy::base_frequency(self.id))
+            .await
+    }
+
     pub async fn energy_perf_bias(&self) -> Result<u64> {
         self.energy_perf_bias
             .get_or_load(policy::energy_perf_bias(self.id))
             .await
     }
 
+    pub async fn epb(&self) -> Result<Epb> {
+        let v = self.energy_perf_bias().await?;
+        match Epb::try_from(v) {
+            Ok(v) => Ok(v),
+            Err(_) => {
+                let cpu = policy::cpus(self.id)
+                    .await?
+                    .first()
+                    .copied()
+                    .unwrap_or(self.id);
+                Err(Error::sysfs_parse(path::energy_perf_bias(cpu), "Epb", v))
+            },
+        }
+    }
+
+    pub async fn epp(&self) -> Result<Epp> {
+        let v = self.energy_performance_preference().await?;
+        v.parse::<Epp>().map_err(|_| {
+            let path = path::energy_performance_preference(self.id);
+            Error::sysfs_parse(path, "Epp", v)
+        })
+    }
+
     pub async fn energy_performance_preference(&self) -> Result<String> {
         self.energy_performance_preference
             .get_or_load(policy::energy_performance_preference(self.id))
@@ -75,6 +120,18 @@ impl Cache {
             .await
     }
 
+    pub async fn set_epb(&self, v: Epb) -> Result<()> {
+        self.energy_perf_bias
+            .clear_if_ok(policy::set_epb(self.id, v))
+            .await
+    }
+
+    pub async fn set_epp(&self, v: Epp) -> Result<()> {
+        self.energy_performance_preference
+            .clear_if_ok(policy::set_epp(self.id, v))
+            .await
+    }
+
     pub async fn set_energy_performance_preference(&self, v: impl AsRef<str>) -> Result<()> {
         self.energy_performance_preference
             .clear_if_ok(policy::set_energy_performance_preference(
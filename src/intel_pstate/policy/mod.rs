@@ -3,16 +3,233 @@ pub mod cache;
 pub(crate) mod path;
 pub mod values;
 
-pub use crate::cpufreq::{exists, ids};
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::cpufreq;
+pub use crate::cpufreq::{exists, housekeeping_ids, ids, ids_for_core_type, non_housekeeping_ids};
 pub use crate::intel_pstate::available;
 #[cfg(feature = "cache")]
 pub use crate::intel_pstate::policy::cache::Cache;
 pub use crate::intel_pstate::policy::values::Values;
 use crate::util::sysfs;
-use crate::Result;
+use crate::{Error, Result};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Epp {
+    Default,
+    Performance,
+    BalancePerformance,
+    BalancePower,
+    Power,
+    Raw(u8),
+}
+
+impl Epp {
+    pub fn from_value(v: u8) -> Self {
+        match v {
+            0 => Self::Performance,
+            128 => Self::BalancePerformance,
+            192 => Self::BalancePower,
+            255 => Self::Power,
+            v => Self::Raw(v),
+        }
+    }
+
+    pub fn value(&self) -> Option<u8> {
+        match self {
+            Self::Default => None,
+            Self::Performance => Some(0),
+            Self::BalancePerformance => Some(128),
+            Self::BalancePower => Some(192),
+            Self::Power => Some(255),
+            Self::Raw(v) => Some(*v),
+        }
+    }
+}
+
+impl Display for Epp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Performance => write!(f, "performance"),
+            Self::BalancePerformance => write!(f, "balance_performance"),
+            Self::BalancePower => write!(f, "balance_power"),
+            Self::Power => write!(f, "power"),
+            Self::Raw(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl FromStr for Epp {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let r = match s {
+            "default" => Self::Default,
+            "performance" => Self::Performance,
+            "balance_performance" => Self::BalancePerformance,
+            "balance_power" => Self::BalancePower,
+            "power" => Self::Power,
+            s => match s.parse::<u8>() {
+                Ok(v) => Self::from_value(v),
+                Err(_) => {
+                    let s = format!("Invalid energy performance preference: {}", s);
+                    return Err(Error::non_sequitor(s));
+                },
+            },
+        };
+        Ok(r)
+    }
+}
+
+// Numeric conversions scale the 0-15 epb range onto the 0-255 epp range.
+impl From<Epb> for Epp {
+    fn from(v: Epb) -> Self {
+        match v {
+            Epb::Performance => Self::Performance,
+            Epb::BalancePerformance => Self::BalancePerformance,
+            Epb::BalancePower => Self::BalancePower,
+            Epb::Power => Self::Power,
+            v => Self::from_value(v.value().saturating_mul(17)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Epb {
+    Performance,
+    BalancePerformance,
+    Normal,
+    BalancePower,
+    Power,
+    Raw(u8),
+}
+
+impl Epb {
+    pub fn from_value(v: u8) -> Self {
+        match v {
+            0 => Self::Performance,
+            4 => Self::BalancePerformance,
+            6 => Self::Normal,
+            8 => Self::BalancePower,
+            15 => Self::Power,
+            v => Self::Raw(v),
+        }
+    }
+
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::Performance => 0,
+            Self::BalancePerformance => 4,
+            Self::Normal => 6,
+            Self::BalancePower => 8,
+            Self::Power => 15,
+            Self::Raw(v) => *v,
+        }
+    }
+}
+
+impl Display for Epb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Performance => write!(f, "performance"),
+            Self::BalancePerformance => write!(f, "balance-performance"),
+            Self::Normal => write!(f, "normal"),
+            Self::BalancePower => write!(f, "balance-power"),
+            Self::Power => write!(f, "power"),
+            Self::Raw(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+impl FromStr for Epb {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let r = match s {
+            "performance" => Self::Performance,
+            "balance-performance" => Self::BalancePerformance,
+            "normal" => Self::Normal,
+            "balance-power" => Self::BalancePower,
+            "power" => Self::Power,
+            s => match s.parse::<u64>() {
+                Ok(v) => Self::try_from(v)?,
+                Err(_) => {
+                    let s = format!("Invalid energy perf bias: {}", s);
+                    return Err(Error::non_sequitor(s));
+                },
+            },
+        };
+        Ok(r)
+    }
+}
+
+// Epb values only range from 0 to 15.
+impl TryFrom<u64> for Epb {
+    type Error = Error;
+
+    fn try_from(v: u64) -> Result<Self> {
+        match u8::try_from(v) {
+            Ok(v) if v <= 15 => Ok(Self::from_value(v)),
+            _ => {
+                let s = format!("Invalid energy perf bias: {}", v);
+                Err(Error::non_sequitor(s))
+            },
+        }
+    }
+}
+
+// The kernel's default epb is `normal`, which `Epp::Default` maps to.
+impl From<Epp> for Epb {
+    fn from(v: Epp) -> Self {
+        match v {
+            Epp::Default => Self::Normal,
+            Epp::Performance => Self::Performance,
+            Epp::BalancePerformance => Self::BalancePerformance,
+            Epp::BalancePower => Self::BalancePower,
+            Epp::Power => Self::Power,
+            Epp::Raw(v) => Self::from_value(((v as u16 + 8) / 17) as u8),
+        }
+    }
+}
+
+// `energy_perf_bias` is a per-cpu attribute, while policy ids only match cpu
+// ids on one-cpu-per-policy systems.
+async fn cpus(id: u64) -> Result<Vec<u64>> {
+    let r = if cpufreq::path::affected_cpus(id).is_file() {
+        cpufreq::affected_cpus(id).await?
+    } else {
+        vec![id]
+    };
+    Ok(r)
+}
+
+pub async fn base_frequency(id: u64) -> Result<u64> {
+    sysfs::read_u64(&path::base_frequency(id)).await
+}
 
 pub async fn energy_perf_bias(id: u64) -> Result<u64> {
-    sysfs::read_u64(&path::energy_perf_bias(id)).await
+    let cpu = cpus(id).await?.first().copied().unwrap_or(id);
+    sysfs::read_u64(&path::energy_perf_bias(cpu)).await
+}
+
+pub async fn epb(id: u64) -> Result<Epb> {
+    let v = energy_perf_bias(id).await?;
+    match Epb::try_from(v) {
+        Ok(v) => Ok(v),
+        Err(_) => {
+            let cpu = cpus(id).await?.first().copied().unwrap_or(id);
+            Err(Error::sysfs_parse(path::energy_perf_bias(cpu), "Epb", v))
+        },
+    }
+}
+
+pub async fn epp(id: u64) -> Result<Epp> {
+    let path = path::energy_performance_preference(id);
+    let v = sysfs::read_string(&path).await?;
+    v.parse::<Epp>()
+        .map_err(|_| Error::sysfs_parse(&path, "Epp", v))
 }
 
 pub async fn energy_performance_preference(id: u64) -> Result<String> {
@@ -24,7 +241,23 @@ pub async fn energy_performance_available_preferences(id: u64) -> Result<Vec<Str
 }
 
 pub async fn set_energy_perf_bias(id: u64, v: u64) -> Result<()> {
-    sysfs::write_u64(&path::energy_perf_bias(id), v).await
+    let cpus = cpus(id).await?;
+    if cpus.is_empty() {
+        let s = format!("cpufreq policy {} has no affected cpus", id);
+        return Err(Error::non_sequitor(s));
+    }
+    for cpu in cpus {
+        sysfs::write_u64(&path::energy_perf_bias(cpu), v).await?;
+    }
+    Ok(())
+}
+
+pub async fn set_epb(id: u64, v: Epb) -> Result<()> {
+    set_energy_perf_bias(id, v.value() as u64).await
+}
+
+pub async fn set_epp(id: u64, v: Epp) -> Result<()> {
+    set_energy_performance_preference(id, &v.to_string()).await
 }
 
 pub async fn set_energy_performance_preference(id: u64, v: &str) -> Result<()> {
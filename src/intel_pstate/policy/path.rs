@@ -3,8 +3,12 @@ use std::path::PathBuf;
 use crate::cpu::path::cpu_attr;
 use crate::cpufreq::path::policy_attr;
 
-pub(crate) fn energy_perf_bias(id: u64) -> PathBuf {
-    cpu_attr(id, "power").join("energy_perf_bias")
+pub(crate) fn base_frequency(id: u64) -> PathBuf {
+    policy_attr(id, "base_frequency")
+}
+
+pub(crate) fn energy_perf_bias(cpu: u64) -> PathBuf {
+    cpu_attr(cpu, "power").join("energy_perf_bias")
 }
 
 pub(crate) fn energy_performance_preference(id: u64) -> PathBuf {
@@ -1,7 +1,8 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-use crate::intel_pstate::policy;
+use crate::cpu::CoreType;
+use crate::intel_pstate::policy::{self, Epb, Epp};
 #[cfg(feature = "cache")]
 use crate::intel_pstate::policy::Cache;
 use crate::Result;
@@ -24,6 +25,18 @@ impl Values {
         policy::ids()
     }
 
+    pub fn ids_for_core_type(core_type: CoreType) -> impl Stream<Item = Result<u64>> {
+        policy::ids_for_core_type(core_type)
+    }
+
+    pub fn housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::housekeeping_ids()
+    }
+
+    pub fn non_housekeeping_ids() -> impl Stream<Item = Result<u64>> {
+        policy::non_housekeeping_ids()
+    }
+
     pub fn all() -> impl Stream<Item = Result<Self>> {
         policy::ids().map_ok(Self::new)
     }
@@ -36,10 +49,22 @@ impl Values {
         self.id
     }
 
+    pub fn base_frequency(&self) -> impl Future<Output = Result<u64>> {
+        policy::base_frequency(self.id)
+    }
+
     pub fn energy_perf_bias(&self) -> impl Future<Output = Result<u64>> {
         policy::energy_perf_bias(self.id)
     }
 
+    pub fn epb(&self) -> impl Future<Output = Result<Epb>> {
+        policy::epb(self.id)
+    }
+
+    pub fn epp(&self) -> impl Future<Output = Result<Epp>> {
+        policy::epp(self.id)
+    }
+
     pub fn energy_performance_preference(&self) -> impl Future<Output = Result<String>> {
         policy::energy_performance_preference(self.id)
     }
@@ -54,6 +79,14 @@ impl Values {
         policy::set_energy_perf_bias(self.id, v)
     }
 
+    pub fn set_epb(&self, v: Epb) -> impl Future<Output = Result<()>> {
+        policy::set_epb(self.id, v)
+    }
+
+    pub fn set_epp(&self, v: Epp) -> impl Future<Output = Result<()>> {
+        policy::set_epp(self.id, v)
+    }
+
     pub async fn set_energy_performance_preference(&self, v: impl AsRef<str>) -> Result<()> {
         policy::set_energy_performance_preference(self.id, v.as_ref()).await
     }
@@ -6,28 +6,50 @@ use crate::Result;
 
 #[derive(Clone, Debug, Default)]
 pub struct Cache {
+    energy_efficiency: Cell<bool>,
+    hwp_dynamic_boost: Cell<bool>,
     max_perf_pct: Cell<u64>,
     min_perf_pct: Cell<u64>,
     no_turbo: Cell<bool>,
+    num_pstates: Cell<u64>,
     status: Cell<String>,
     turbo_pct: Cell<u64>,
 }
 
 impl Cache {
+    pub const ACTIVE: &'static str = crate::intel_pstate::system::ACTIVE;
+    pub const OFF: &'static str = crate::intel_pstate::system::OFF;
+    pub const PASSIVE: &'static str = crate::intel_pstate::system::PASSIVE;
+
     pub fn available() -> impl Future<Output = Result<bool>> {
         system::available()
     }
 
     pub async fn clear(&self) {
         tokio::join!(
+            self.energy_efficiency.clear(),
+            self.hwp_dynamic_boost.clear(),
             self.max_perf_pct.clear(),
             self.min_perf_pct.clear(),
             self.no_turbo.clear(),
+            self.num_pstates.clear(),
             self.status.clear(),
             self.turbo_pct.clear(),
         );
     }
 
+    pub async fn energy_efficiency(&self) -> Result<bool> {
+        self.energy_efficiency
+            .get_or_load(system::energy_efficiency())
+            .await
+    }
+
+    pub async fn hwp_dynamic_boost(&self) -> Result<bool> {
+        self.hwp_dynamic_boost
+            .get_or_load(system::hwp_dynamic_boost())
+            .await
+    }
+
     pub async fn max_perf_pct(&self) -> Result<u64> {
         self.max_perf_pct.get_or_load(system::max_perf_pct()).await
     }
@@ -40,18 +62,34 @@ impl Cache {
         self.no_turbo.get_or_load(system::no_turbo()).await
     }
 
+    pub async fn num_pstates(&self) -> Result<u64> {
+        self.num_pstates.get_or_load(system::num_pstates()).await
+    }
+
     pub async fn status(&self) -> Result<String> {
         self.status.get_or_load(system::status()).await
     }
 
     pub async fn is_active(&self) -> Result<bool> {
-        self.status().await.map(|v| v == "active")
+        self.status().await.map(|v| v == system::ACTIVE)
     }
 
     pub async fn turbo_pct(&self) -> Result<u64> {
         self.turbo_pct.get_or_load(system::turbo_pct()).await
     }
 
+    pub async fn set_energy_efficiency(&self, v: bool) -> Result<()> {
+        self.energy_efficiency
+            .clear_if_ok(system::set_energy_efficiency(v))
+            .await
+    }
+
+    pub async fn set_hwp_dynamic_boost(&self, v: bool) -> Result<()> {
+        self.hwp_dynamic_boost
+            .clear_if_ok(system::set_hwp_dynamic_boost(v))
+            .await
+    }
+
     pub async fn set_max_perf_pct(&self, v: u64) -> Result<()> {
         self.max_perf_pct
             .clear_if_ok(system::set_max_perf_pct(v))
@@ -67,6 +105,12 @@ impl Cache {
     pub async fn set_no_turbo(&self, v: bool) -> Result<()> {
         self.no_turbo.clear_if_ok(system::set_no_turbo(v)).await
     }
+
+    pub async fn set_status(&self, v: impl AsRef<str>) -> Result<()> {
+        system::set_status(v.as_ref()).await?;
+        self.clear().await;
+        Ok(())
+    }
 }
 
 impl From<Values> for Cache {
@@ -3,12 +3,46 @@ mod cache;
 pub(crate) mod path;
 mod values;
 
+use std::path::PathBuf;
+
 pub use crate::intel_pstate::available;
 #[cfg(feature = "cache")]
 pub use crate::intel_pstate::system::cache::Cache;
 pub use crate::intel_pstate::system::values::Values;
 use crate::util::sysfs;
-use crate::Result;
+use crate::{Error, Result};
+
+pub const ACTIVE: &str = "active";
+pub const PASSIVE: &str = "passive";
+pub const OFF: &str = "off";
+
+// Whether some global attributes exist depends on the driver mode and on cpu
+// support, e.g. `hwp_dynamic_boost` needs hwp and active mode, `num_pstates`
+// and `turbo_pct` are missing with hwp, and `energy_efficiency` is only
+// present on some cpu models.
+async fn attr(path: PathBuf) -> Result<PathBuf> {
+    if path.is_file() {
+        Ok(path)
+    } else {
+        let status = status().await?;
+        let name = path.file_name().and_then(|v| v.to_str()).unwrap_or("");
+        let s = format!(
+            "intel_pstate attribute {} is not present (driver status: {})",
+            name, status
+        );
+        #[cfg(feature = "logging")]
+        log::error!("ERR {}", s);
+        Err(Error::non_sequitor(s))
+    }
+}
+
+pub async fn energy_efficiency() -> Result<bool> {
+    sysfs::read_bool(&attr(path::energy_efficiency()).await?).await
+}
+
+pub async fn hwp_dynamic_boost() -> Result<bool> {
+    sysfs::read_bool(&attr(path::hwp_dynamic_boost()).await?).await
+}
 
 pub async fn max_perf_pct() -> Result<u64> {
     sysfs::read_u64(&path::max_perf_pct()).await
@@ -22,12 +56,24 @@ pub async fn no_turbo() -> Result<bool> {
     sysfs::read_bool(&path::no_turbo()).await
 }
 
+pub async fn num_pstates() -> Result<u64> {
+    sysfs::read_u64(&attr(path::num_pstates()).await?).await
+}
+
 pub async fn status() -> Result<String> {
     sysfs::read_string(&path::status()).await
 }
 
 pub async fn turbo_pct() -> Result<u64> {
-    sysfs::read_u64(&path::turbo_pct()).await
+    sysfs::read_u64(&attr(path::turbo_pct()).await?).await
+}
+
+pub async fn set_energy_efficiency(v: bool) -> Result<()> {
+    sysfs::write_bool(&attr(path::energy_efficiency()).await?, v).await
+}
+
+pub async fn set_hwp_dynamic_boost(v: bool) -> Result<()> {
+    sysfs::write_bool(&attr(path::hwp_dynamic_boost()).await?, v).await
 }
 
 pub async fn set_max_perf_pct(v: u64) -> Result<()> {
@@ -41,3 +87,7 @@ pub async fn set_min_perf_pct(v: u64) -> Result<()> {
 pub async fn set_no_turbo(v: bool) -> Result<()> {
     sysfs::write_bool(&path::no_turbo(), v).await
 }
+
+pub async fn set_status(v: &str) -> Result<()> {
+    sysfs::write_string(&path::status(), v).await
+}
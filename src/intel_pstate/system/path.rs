@@ -8,6 +8,14 @@ pub(crate) fn root_attr(a: &str) -> PathBuf {
     root().join(a)
 }
 
+pub(crate) fn energy_efficiency() -> PathBuf {
+    root_attr("energy_efficiency")
+}
+
+pub(crate) fn hwp_dynamic_boost() -> PathBuf {
+    root_attr("hwp_dynamic_boost")
+}
+
 pub(crate) fn max_perf_pct() -> PathBuf {
     root_attr("max_perf_pct")
 }
@@ -20,6 +28,10 @@ pub(crate) fn no_turbo() -> PathBuf {
     root_attr("no_turbo")
 }
 
+pub(crate) fn num_pstates() -> PathBuf {
+    root_attr("num_pstates")
+}
+
 pub(crate) fn status() -> PathBuf {
     root_attr("status")
 }
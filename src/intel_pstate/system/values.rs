@@ -10,10 +10,22 @@ use crate::Result;
 pub struct Values;
 
 impl Values {
+    pub const ACTIVE: &'static str = crate::intel_pstate::system::ACTIVE;
+    pub const OFF: &'static str = crate::intel_pstate::system::OFF;
+    pub const PASSIVE: &'static str = crate::intel_pstate::system::PASSIVE;
+
     pub fn available() -> impl Future<Output = Result<bool>> {
         system::available()
     }
 
+    pub fn energy_efficiency(&self) -> impl Future<Output = Result<bool>> {
+        system::energy_efficiency()
+    }
+
+    pub fn hwp_dynamic_boost(&self) -> impl Future<Output = Result<bool>> {
+        system::hwp_dynamic_boost()
+    }
+
     pub fn max_perf_pct(&self) -> impl Future<Output = Result<u64>> {
         system::max_perf_pct()
     }
@@ -26,18 +38,30 @@ impl Values {
         system::no_turbo()
     }
 
+    pub fn num_pstates(&self) -> impl Future<Output = Result<u64>> {
+        system::num_pstates()
+    }
+
     pub fn status(&self) -> impl Future<Output = Result<String>> {
         system::status()
     }
 
     pub async fn is_active(&self) -> Result<bool> {
-        self.status().await.map(|v| v == "active")
+        self.status().await.map(|v| v == system::ACTIVE)
     }
 
     pub fn turbo_pct(&self) -> impl Future<Output = Result<u64>> {
         system::turbo_pct()
     }
 
+    pub fn set_energy_efficiency(&self, v: bool) -> impl Future<Output = Result<()>> {
+        system::set_energy_efficiency(v)
+    }
+
+    pub fn set_hwp_dynamic_boost(&self, v: bool) -> impl Future<Output = Result<()>> {
+        system::set_hwp_dynamic_boost(v)
+    }
+
     pub fn set_max_perf_pct(&self, v: u64) -> impl Future<Output = Result<()>> {
         system::set_max_perf_pct(v)
     }
@@ -49,18 +73,22 @@ impl Values {
     pub fn set_no_turbo(&self, v: bool) -> impl Future<Output = Result<()>> {
         system::set_no_turbo(v)
     }
+
+    pub async fn set_status(&self, v: impl AsRef<str>) -> Result<()> {
+        system::set_status(v.as_ref()).await
+    }
 }
 
 #[cfg(feature = "cache")]
 impl From<Cache> for Values {
     fn from(_: Cache) -> Self {
-        Self::default()
+        Self
     }
 }
 
 #[cfg(feature = "cache")]
 impl From<&Cache> for Values {
     fn from(_: &Cache) -> Self {
-        Self::default()
+        Self
     }
 }
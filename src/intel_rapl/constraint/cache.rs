@@ -1,7 +1,6 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-pub use crate::intel_rapl::available;
 use crate::intel_rapl::constraint::{self, Id, Values};
 use crate::intel_rapl::zone;
 use crate::util::cell::Cell;
@@ -10,8 +9,12 @@ use crate::Result;
 #[derive(Clone, Debug)]
 pub struct Cache {
     id: Id,
+    enabled: Cell<bool>,
     name: Cell<String>,
     max_power_uw: Cell<u64>,
+    max_time_window_us: Cell<u64>,
+    min_power_uw: Cell<u64>,
+    min_time_window_us: Cell<u64>,
     power_limit_uw: Cell<u64>,
     time_window_us: Cell<u64>,
 }
@@ -59,8 +62,12 @@ impl Cache {
     pub fn new(id: impl Into<Id>) -> Self {
         Self {
             id: id.into(),
+            enabled: Cell::default(),
             name: Cell::default(),
             max_power_uw: Cell::default(),
+            max_time_window_us: Cell::default(),
+            min_power_uw: Cell::default(),
+            min_time_window_us: Cell::default(),
             power_limit_uw: Cell::default(),
             time_window_us: Cell::default(),
         }
@@ -68,8 +75,12 @@ impl Cache {
 
     pub async fn clear(&self) {
         tokio::join!(
+            self.enabled.clear(),
             self.name.clear(),
             self.max_power_uw.clear(),
+            self.max_time_window_us.clear(),
+            self.min_power_uw.clear(),
+            self.min_time_window_us.clear(),
             self.power_limit_uw.clear(),
             self.time_window_us.clear(),
         );
@@ -79,6 +90,10 @@ impl Cache {
         self.id
     }
 
+    pub async fn enabled(&self) -> Result<bool> {
+        self.enabled.get_or_load(constraint::enabled(self.id)).await
+    }
+
     pub async fn name(&self) -> Result<String> {
         self.name.get_or_load(constraint::name(self.id)).await
     }
@@ -89,6 +104,24 @@ impl Cache {
             .await
     }
 
+    pub async fn max_time_window_us(&self) -> Result<u64> {
+        self.max_time_window_us
+            .get_or_load(constraint::max_time_window_us(self.id))
+            .await
+    }
+
+    pub async fn min_power_uw(&self) -> Result<u64> {
+        self.min_power_uw
+            .get_or_load(constraint::min_power_uw(self.id))
+            .await
+    }
+
+    pub async fn min_time_window_us(&self) -> Result<u64> {
+        self.min_time_window_us
+            .get_or_load(constraint::min_time_window_us(self.id))
+            .await
+    }
+
     pub async fn power_limit_uw(&self) -> Result<u64> {
         self.power_limit_uw
             .get_or_load(constraint::power_limit_uw(self.id))
@@ -101,6 +134,11 @@ impl Cache {
             .await
     }
 
+    pub async fn set_enabled(&self, v: bool) -> Result<()> {
+        let f = constraint::set_enabled(self.id, v);
+        self.enabled.clear_if_ok(f).await
+    }
+
     pub async fn set_power_limit_uw(&self, v: u64) -> Result<()> {
         let f = constraint::set_power_limit_uw(self.id, v);
         self.power_limit_uw.clear_if_ok(f).await
@@ -1,10 +1,7 @@
 #[cfg(feature = "cache")]
 mod cache;
-pub(crate) mod path;
 mod values;
 
-use async_stream::stream;
-use futures::pin_mut;
 use futures::stream::{Stream, TryStreamExt as _};
 
 pub use crate::intel_rapl::available;
@@ -12,8 +9,7 @@ pub use crate::intel_rapl::available;
 pub use crate::intel_rapl::constraint::cache::Cache;
 pub use crate::intel_rapl::constraint::values::Values;
 use crate::intel_rapl::zone::{ids as zone_ids, Id as ZoneId};
-use crate::util::sysfs;
-use crate::Result;
+use crate::{powercap, Error, Result};
 
 pub const LONG_TERM: &str = "long_term";
 pub const SHORT_TERM: &str = "short_term";
@@ -82,67 +78,82 @@ impl From<Id> for ZoneId {
     }
 }
 
+impl From<Id> for powercap::constraint::Id {
+    fn from(v: Id) -> Self {
+        Self::new(ZoneId::from(v), v.index)
+    }
+}
+
+impl TryFrom<powercap::constraint::Id> for Id {
+    type Error = Error;
+
+    fn try_from(v: powercap::constraint::Id) -> Result<Self> {
+        let zone = ZoneId::try_from(v.zone().clone())?;
+        Ok(Self::from((zone, v.index())))
+    }
+}
+
 pub fn ids() -> impl Stream<Item = Result<Id>> {
     zone_ids().map_ok(ids_for_zone).try_flatten()
 }
 
 pub fn ids_for_zone(zone: impl Into<ZoneId>) -> impl Stream<Item = Result<Id>> {
-    let zone = zone.into();
-    stream! {
-        let zone = zone.into();
-        for c in 0.. {
-            let id = Id::from((zone, c));
-            if path::name(id).is_file() {
-                yield Ok(id);
-            } else {
-                break;
-            }
-        }
-    }
+    powercap::constraint::ids_for_zone(zone.into()).and_then(|v| async move { Id::try_from(v) })
 }
 
-pub async fn id_for_name<Z, S>(zone: Z, name_: S) -> Result<Option<Id>>
+pub async fn id_for_name<Z, S>(zone: Z, name: S) -> Result<Option<Id>>
 where
     Z: Into<ZoneId>,
     S: Into<String>,
 {
-    let (zone, name_) = (zone.into(), name_.into());
-    let mut r = None;
-    let s = ids_for_zone(zone);
-    pin_mut!(s);
-    while let Some(v) = s.try_next().await? {
-        if name_ == name(v).await? {
-            r = Some(v);
-            break;
-        }
-    }
-    Ok(r)
+    let r = powercap::constraint::id_for_name(zone.into(), name).await?;
+    r.map(Id::try_from).transpose()
 }
 
 pub async fn exists(id: impl Into<Id>) -> Result<bool> {
-    Ok(path::name(id.into()).is_file())
+    powercap::constraint::exists(id.into()).await
+}
+
+pub async fn enabled(id: impl Into<Id>) -> Result<bool> {
+    powercap::constraint::enabled(id.into()).await
 }
 
 pub async fn name(id: impl Into<Id>) -> Result<String> {
-    sysfs::read_string(&path::name(id.into())).await
+    powercap::constraint::name(id.into()).await
 }
 
 pub async fn max_power_uw(id: impl Into<Id>) -> Result<u64> {
-    sysfs::read_u64(&path::max_power_uw(id.into())).await
+    powercap::constraint::max_power_uw(id.into()).await
+}
+
+pub async fn max_time_window_us(id: impl Into<Id>) -> Result<u64> {
+    powercap::constraint::max_time_window_us(id.into()).await
+}
+
+pub async fn min_power_uw(id: impl Into<Id>) -> Result<u64> {
+    powercap::constraint::min_power_uw(id.into()).await
+}
+
+pub async fn min_time_window_us(id: impl Into<Id>) -> Result<u64> {
+    powercap::constraint::min_time_window_us(id.into()).await
 }
 
 pub async fn power_limit_uw(id: impl Into<Id>) -> Result<u64> {
-    sysfs::read_u64(&path::power_limit_uw(id.into())).await
+    powercap::constraint::power_limit_uw(id.into()).await
 }
 
 pub async fn time_window_us(id: impl Into<Id>) -> Result<u64> {
-    sysfs::read_u64(&path::time_window_us(id.into())).await
+    powercap::constraint::time_window_us(id.into()).await
+}
+
+pub async fn set_enabled(id: impl Into<Id>, v: bool) -> Result<()> {
+    powercap::constraint::set_enabled(id.into(), v).await
 }
 
 pub async fn set_power_limit_uw(id: impl Into<Id>, v: u64) -> Result<()> {
-    sysfs::write_u64(&path::power_limit_uw(id.into()), v).await
+    powercap::constraint::set_power_limit_uw(id.into(), v).await
 }
 
 pub async fn set_time_window_us(id: impl Into<Id>, v: u64) -> Result<()> {
-    sysfs::write_u64(&path::time_window_us(id.into()), v).await
+    powercap::constraint::set_time_window_us(id.into(), v).await
 }
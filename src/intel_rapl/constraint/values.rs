@@ -1,7 +1,6 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-pub use crate::intel_rapl::available;
 use crate::intel_rapl::constraint::{self, Id};
 #[cfg(feature = "cache")]
 use crate::intel_rapl::constraint::Cache;
@@ -62,6 +61,10 @@ impl Values {
         self.id
     }
 
+    pub fn enabled(&self) -> impl Future<Output = Result<bool>> {
+        constraint::enabled(self.id)
+    }
+
     pub fn name(&self) -> impl Future<Output = Result<String>> {
         constraint::name(self.id)
     }
@@ -70,6 +73,18 @@ impl Values {
         constraint::max_power_uw(self.id)
     }
 
+    pub fn max_time_window_us(&self) -> impl Future<Output = Result<u64>> {
+        constraint::max_time_window_us(self.id)
+    }
+
+    pub fn min_power_uw(&self) -> impl Future<Output = Result<u64>> {
+        constraint::min_power_uw(self.id)
+    }
+
+    pub fn min_time_window_us(&self) -> impl Future<Output = Result<u64>> {
+        constraint::min_time_window_us(self.id)
+    }
+
     pub fn power_limit_uw(&self) -> impl Future<Output = Result<u64>> {
         constraint::power_limit_uw(self.id)
     }
@@ -78,6 +93,10 @@ impl Values {
         constraint::time_window_us(self.id)
     }
 
+    pub fn set_enabled(&self, v: bool) -> impl Future<Output = Result<()>> {
+        constraint::set_enabled(self.id, v)
+    }
+
     pub fn set_power_limit_uw(&self, v: u64) -> impl Future<Output = Result<()>> {
         constraint::set_power_limit_uw(self.id, v)
     }
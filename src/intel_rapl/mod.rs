@@ -1,9 +1,10 @@
 pub mod constraint;
-pub(crate) mod path;
 pub mod zone;
 
-use crate::Result;
+use crate::{powercap, Result};
+
+pub const CONTROL_TYPE: &str = powercap::INTEL_RAPL;
 
 pub async fn available() -> Result<bool> {
-    Ok(path::root().is_dir())
+    powercap::exists(CONTROL_TYPE).await
 }
@@ -1,9 +1,9 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-use crate::intel_rapl::zone::{self, Id, Values};
+use crate::intel_rapl::zone::{self, Id, Values, ZoneKind};
 use crate::util::cell::Cell;
-use crate::Result;
+use crate::{powercap, Error, Result};
 
 #[derive(Clone, Debug)]
 pub struct Cache {
@@ -31,6 +31,14 @@ impl Cache {
         zone::ids().map_ok(Self::new)
     }
 
+    pub fn find(kind: ZoneKind, package: u64) -> impl Future<Output = Result<Option<Id>>> {
+        zone::find(kind, package)
+    }
+
+    pub async fn for_kind(kind: ZoneKind, package: u64) -> Result<Option<Self>> {
+        Ok(zone::find(kind, package).await?.map(Self::new))
+    }
+
     pub fn new(id: impl Into<Id>) -> Self {
         Self {
             id: id.into(),
@@ -68,6 +76,14 @@ impl Cache {
             .await
     }
 
+    pub async fn kind(&self) -> Result<ZoneKind> {
+        let v = self.name().await?;
+        v.parse::<ZoneKind>().map_err(|_| {
+            let path = powercap::zone::path::name(&self.id.into());
+            Error::sysfs_parse(path, "ZoneKind", v)
+        })
+    }
+
     pub async fn name(&self) -> Result<String> {
         self.name.get_or_load(zone::name(self.id)).await
     }
@@ -0,0 +1,163 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use async_stream::try_stream;
+use futures::future::try_join_all;
+use futures::stream::{Stream, TryStreamExt as _};
+use tokio::time::{Instant, MissedTickBehavior};
+
+use crate::intel_rapl::zone::{self, ZoneKind};
+use crate::powercap::zone::Id;
+use crate::{powercap, Result};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PowerSample {
+    pub elapsed: Duration,
+    pub energy_uj: BTreeMap<Id, u64>,
+    pub power_uw: BTreeMap<Id, u64>,
+    pub avg_power_uw: BTreeMap<Id, u64>,
+}
+
+impl PowerSample {
+    pub fn total_energy_uj(&self) -> u64 {
+        self.energy_uj.values().sum()
+    }
+
+    pub fn total_power_uw(&self) -> u64 {
+        self.power_uw.values().sum()
+    }
+
+    pub fn total_avg_power_uw(&self) -> u64 {
+        self.avg_power_uw.values().sum()
+    }
+}
+
+fn power_uw(energy_uj: u64, elapsed: Duration) -> u64 {
+    (energy_uj as u128 * 1_000_000)
+        .checked_div(elapsed.as_micros())
+        .unwrap_or_default() as u64
+}
+
+// Top-level zones named `package-N`. Psys is also a top-level zone but its
+// energy already includes that of the packages.
+pub(crate) fn package_ids() -> impl Stream<Item = Result<Id>> {
+    zone::packages().try_filter_map(|id| async move {
+        let v = zone::name(id).await?;
+        Ok(matches!(v.parse(), Ok(ZoneKind::Package)).then(|| id.into()))
+    })
+}
+
+#[derive(Clone, Debug)]
+pub(crate) struct Counter {
+    pub(crate) id: Id,
+    range: u64,
+    start: Instant,
+    last: Instant,
+    energy_uj: u64,
+    pub(crate) total_uj: u64,
+}
+
+impl Counter {
+    pub(crate) async fn new(id: Id) -> Result<Self> {
+        let (range, energy_uj) = tokio::try_join!(
+            powercap::zone::max_energy_range_uj(id.clone()),
+            powercap::zone::energy_uj(id.clone()),
+        )?;
+        let now = Instant::now();
+        Ok(Self {
+            id,
+            range,
+            start: now,
+            last: now,
+            energy_uj,
+            total_uj: 0,
+        })
+    }
+
+    // `energy_uj` counts from zero to `max_energy_range_uj` inclusive, so a
+    // wrap covers `max_energy_range_uj + 1`.
+    pub(crate) async fn update(&mut self) -> Result<(u64, Duration)> {
+        let energy_uj = powercap::zone::energy_uj(self.id.clone()).await?;
+        let now = Instant::now();
+        let delta = if energy_uj >= self.energy_uj {
+            energy_uj - self.energy_uj
+        } else {
+            self.range
+                .saturating_sub(self.energy_uj)
+                .saturating_add(energy_uj)
+                .saturating_add(1)
+        };
+        let elapsed = now - self.last;
+        self.energy_uj = energy_uj;
+        self.last = now;
+        self.total_uj = self.total_uj.saturating_add(delta);
+        Ok((delta, elapsed))
+    }
+
+    fn avg_power_uw(&self) -> u64 {
+        power_uw(self.total_uj, self.last - self.start)
+    }
+}
+
+// Meters take powercap zone ids so that zones of other rapl control types,
+// such as `intel-rapl-mmio`, can be measured alongside the msr ones.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PowerMeter {
+    ids: Vec<Id>,
+}
+
+impl PowerMeter {
+    pub fn new<I, T>(ids: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Id>,
+    {
+        let ids = ids.into_iter().map(Into::into).collect();
+        Self { ids }
+    }
+
+    pub async fn packages() -> Result<Self> {
+        let ids: Vec<_> = package_ids().try_collect().await?;
+        Ok(Self::new(ids))
+    }
+
+    pub fn ids(&self) -> &[Id] {
+        &self.ids
+    }
+
+    async fn counters(&self) -> Result<Vec<Counter>> {
+        try_join_all(self.ids.iter().cloned().map(Counter::new)).await
+    }
+
+    async fn sample(counters: &mut [Counter]) -> Result<PowerSample> {
+        let updates = try_join_all(counters.iter_mut().map(|c| c.update())).await?;
+        let mut r = PowerSample::default();
+        for (c, (delta, elapsed)) in counters.iter().zip(updates) {
+            r.elapsed = r.elapsed.max(elapsed);
+            r.energy_uj.insert(c.id.clone(), delta);
+            r.power_uw.insert(c.id.clone(), power_uw(delta, elapsed));
+            r.avg_power_uw.insert(c.id.clone(), c.avg_power_uw());
+        }
+        Ok(r)
+    }
+
+    pub async fn measure(&self, duration: Duration) -> Result<PowerSample> {
+        let mut counters = self.counters().await?;
+        tokio::time::sleep(duration).await;
+        Self::sample(&mut counters).await
+    }
+
+    pub fn sampler(&self, interval: Duration) -> impl Stream<Item = Result<PowerSample>> {
+        let meter = self.clone();
+        try_stream! {
+            let mut interval = tokio::time::interval(interval);
+            interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            interval.tick().await;
+            let mut counters = meter.counters().await?;
+            loop {
+                interval.tick().await;
+                yield Self::sample(&mut counters).await?;
+            }
+        }
+    }
+}
@@ -1,17 +1,63 @@
 #[cfg(feature = "cache")]
 mod cache;
-pub(crate) mod path;
+pub(crate) mod meter;
 mod values;
 
+use std::fmt::Display;
+use std::str::FromStr;
+
 use async_stream::try_stream;
+use futures::pin_mut;
 use futures::stream::{Stream, TryStreamExt as _};
 
 pub use crate::intel_rapl::available;
 #[cfg(feature = "cache")]
 pub use crate::intel_rapl::zone::cache::Cache;
+pub use crate::intel_rapl::zone::meter::{PowerMeter, PowerSample};
 pub use crate::intel_rapl::zone::values::Values;
-use crate::util::sysfs;
-use crate::Result;
+use crate::intel_rapl::CONTROL_TYPE;
+use crate::{powercap, Error, Result};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ZoneKind {
+    Package,
+    Core,
+    Uncore,
+    Dram,
+    Psys,
+}
+
+impl Display for ZoneKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Package => write!(f, "package"),
+            Self::Core => write!(f, "core"),
+            Self::Uncore => write!(f, "uncore"),
+            Self::Dram => write!(f, "dram"),
+            Self::Psys => write!(f, "psys"),
+        }
+    }
+}
+
+// Package zones are named `package-N`, where `N` is the physical package.
+impl FromStr for ZoneKind {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let r = match s {
+            "core" => Self::Core,
+            "uncore" => Self::Uncore,
+            "dram" => Self::Dram,
+            "psys" => Self::Psys,
+            s if s == "package" || s.starts_with("package-") => Self::Package,
+            s => {
+                let s = format!("Invalid zone kind: {}", s);
+                return Err(Error::non_sequitor(s));
+            },
+        };
+        Ok(r)
+    }
+}
 
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Id {
@@ -51,24 +97,40 @@ impl From<Id> for (u64, Option<u64>) {
     }
 }
 
-pub fn packages() -> impl Stream<Item = Result<Id>> {
-    sysfs::read_ids(&path::root(), "intel-rapl:")
-        .and_then(|v| async move { Ok(Id::from((v, None))) })
+impl From<Id> for powercap::zone::Id {
+    fn from(v: Id) -> Self {
+        Self::new(CONTROL_TYPE, [v.package].into_iter().chain(v.subzone))
+    }
 }
 
-pub fn subzones(package: u64) -> impl Stream<Item = Result<Id>> {
-    try_stream! {
-        let path = path::package(package);
-        let prefix = format!("intel-rapl:{}:", package);
-        let s = sysfs::read_ids(&path, &prefix);
-        for await v in s {
-            let v = v?;
-            let r = Id::from((package, Some(v)));
-            yield r;
+impl TryFrom<powercap::zone::Id> for Id {
+    type Error = Error;
+
+    fn try_from(v: powercap::zone::Id) -> Result<Self> {
+        match (v.control_type(), v.indices()) {
+            (CONTROL_TYPE, [p]) => Ok(Self::new(*p, None)),
+            (CONTROL_TYPE, [p, s]) => Ok(Self::new(*p, Some(*s))),
+            _ => {
+                let s = format!("Not an intel_rapl zone: {}", v);
+                Err(Error::non_sequitor(s))
+            },
         }
     }
 }
 
+pub fn packages() -> impl Stream<Item = Result<Id>> {
+    powercap::zone::ids_for_control_type(CONTROL_TYPE)
+        .try_filter(|v| {
+            let r = v.indices().len() == 1;
+            async move { r }
+        })
+        .and_then(|v| async move { Id::try_from(v) })
+}
+
+pub fn subzones(package: u64) -> impl Stream<Item = Result<Id>> {
+    powercap::zone::children(Id::new(package, None)).and_then(|v| async move { Id::try_from(v) })
+}
+
 pub fn ids() -> impl Stream<Item = Result<Id>> {
     try_stream! {
         for await p in packages() {
@@ -83,31 +145,65 @@ pub fn ids() -> impl Stream<Item = Result<Id>> {
 }
 
 pub async fn exists(id: impl Into<Id>) -> Result<bool> {
-    let id = id.into();
-    let r = if let Some(subzone) = &id.subzone {
-        path::subzone(id.package, *subzone).is_dir()
-    } else {
-        path::package(id.package).is_dir()
-    };
-    Ok(r)
+    powercap::zone::exists(id.into()).await
 }
 
 pub async fn enabled(id: impl Into<Id>) -> Result<bool> {
-    sysfs::read_bool(&path::enabled(id.into())).await
+    powercap::zone::enabled(id.into()).await
 }
 
 pub async fn energy_uj(id: impl Into<Id>) -> Result<u64> {
-    sysfs::read_u64(&path::energy_uj(id.into())).await
+    powercap::zone::energy_uj(id.into()).await
 }
 
 pub async fn max_energy_range_uj(id: impl Into<Id>) -> Result<u64> {
-    sysfs::read_u64(&path::max_energy_range_uj(id.into())).await
+    powercap::zone::max_energy_range_uj(id.into()).await
 }
 
 pub async fn name(id: impl Into<Id>) -> Result<String> {
-    sysfs::read_string(&path::name(id.into())).await
+    powercap::zone::name(id.into()).await
+}
+
+pub async fn kind(id: impl Into<Id>) -> Result<ZoneKind> {
+    let id = id.into();
+    let v = name(id).await?;
+    v.parse::<ZoneKind>().map_err(|_| {
+        let path = powercap::zone::path::name(&id.into());
+        Error::sysfs_parse(path, "ZoneKind", v)
+    })
+}
+
+// `package` is the physical package number from the `package-N` zone name,
+// which need not match the zone index, e.g. when `psys` is `intel-rapl:1`.
+// Psys is platform-wide and is returned regardless of `package`.
+pub async fn find(kind: ZoneKind, package: u64) -> Result<Option<Id>> {
+    let zone_name = match kind {
+        ZoneKind::Psys => kind.to_string(),
+        _ => format!("package-{}", package),
+    };
+    let s = packages();
+    pin_mut!(s);
+    let mut zone = None;
+    while let Some(id) = s.try_next().await? {
+        if name(id).await? == zone_name {
+            zone = Some(id);
+            break;
+        }
+    }
+    let zone = match (kind, zone) {
+        (ZoneKind::Package | ZoneKind::Psys, zone) | (_, zone @ None) => return Ok(zone),
+        (_, Some(zone)) => zone,
+    };
+    let s = subzones(zone.package);
+    pin_mut!(s);
+    while let Some(id) = s.try_next().await? {
+        if name(id).await? == kind.to_string() {
+            return Ok(Some(id));
+        }
+    }
+    Ok(None)
 }
 
 pub async fn set_enabled(id: impl Into<Id>, v: bool) -> Result<()> {
-    sysfs::write_bool(&path::enabled(id.into()), v).await
+    powercap::zone::set_enabled(id.into(), v).await
 }
@@ -1,7 +1,7 @@
 use futures::stream::{Stream, TryStreamExt as _};
 use futures::Future;
 
-use crate::intel_rapl::zone::{self, Id};
+use crate::intel_rapl::zone::{self, Id, ZoneKind};
 #[cfg(feature = "cache")]
 use crate::intel_rapl::zone::Cache;
 
@@ -29,6 +29,14 @@ impl Values {
         zone::ids().map_ok(Self::new)
     }
 
+    pub fn find(kind: ZoneKind, package: u64) -> impl Future<Output = Result<Option<Id>>> {
+        zone::find(kind, package)
+    }
+
+    pub async fn for_kind(kind: ZoneKind, package: u64) -> Result<Option<Self>> {
+        Ok(zone::find(kind, package).await?.map(Self::new))
+    }
+
     pub fn new(id: impl Into<Id>) -> Self {
         Self { id: id.into() }
     }
@@ -49,6 +57,10 @@ impl Values {
         zone::max_energy_range_uj(self.id)
     }
 
+    pub fn kind(&self) -> impl Future<Output = Result<ZoneKind>> {
+        zone::kind(self.id)
+    }
+
     pub fn name(&self) -> impl Future<Output = Result<String>> {
         zone::name(self.id)
     }
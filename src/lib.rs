@@ -1,13 +1,22 @@
-//pub mod amdgpu;
+pub mod amd_pstate;
+pub mod amdgpu;
+pub mod budget;
+pub mod control;
 pub mod cpu;
 pub mod cpufreq;
 pub mod drm;
+pub mod energy;
+pub mod freq;
 pub mod i915;
 pub mod intel_pstate;
 pub mod intel_rapl;
+#[cfg(feature = "msr")]
+pub mod msr;
 #[cfg(feature = "nvml")]
 pub mod nvml;
+pub mod powercap;
 mod util;
+pub mod xe;
 
 use std::fmt::Display;
 pub use std::io::Error as IoError;
@@ -44,6 +53,21 @@ pub enum Error {
         op: Op,
     },
 
+    #[error("{path}: power limits for {zone} are locked by firmware")]
+    PowercapLocked {
+        #[source]
+        source: IoError,
+        path: PathBuf,
+        zone: String,
+    },
+
+    #[error("process: {program}: {source}")]
+    Process {
+        #[source]
+        source: IoError,
+        program: String,
+    },
+
     #[error("parse: {path}: Invalid value for {ty}: {value:?}")]
     SysfsParse {
         path: PathBuf,
@@ -94,6 +118,16 @@ impl Error {
         Self::SysfsParse { path, ty, value }
     }
 
+    fn powercap_locked(source: IoError, path: PathBuf, zone: impl Display) -> Self {
+        let zone = zone.to_string();
+        Self::PowercapLocked { source, path, zone }
+    }
+
+    fn process(source: IoError, program: impl Display) -> Self {
+        let program = program.to_string();
+        Self::Process { source, program }
+    }
+
     #[cfg(feature = "nvml")]
     fn nvml_init(error: &'static NvmlError) -> Self {
         Self::NvmlInit(error)
@@ -0,0 +1,470 @@
+pub(crate) mod path;
+mod values;
+
+use std::io::SeekFrom;
+use std::path::Path;
+
+use futures::stream::{Stream, TryStreamExt as _};
+use tokio::fs::OpenOptions;
+use tokio::io::{AsyncReadExt as _, AsyncSeekExt as _, AsyncWriteExt as _};
+
+pub use crate::msr::values::Values;
+use crate::util::sysfs;
+use crate::{Error, Result};
+
+pub const IA32_ENERGY_PERF_BIAS: u64 = 0x1b0;
+pub const IA32_HWP_CAPABILITIES: u64 = 0x771;
+pub const IA32_HWP_REQUEST: u64 = 0x774;
+pub const MSR_RAPL_POWER_UNIT: u64 = 0x606;
+pub const MSR_PKG_POWER_LIMIT: u64 = 0x610;
+
+fn bits(v: u64, lo: u32, len: u32) -> u64 {
+    (v >> lo) & ((1 << len) - 1)
+}
+
+fn set_bits(v: u64, lo: u32, len: u32, field: u64) -> Result<u64> {
+    let max = (1 << len) - 1;
+    if field > max {
+        let s = format!(
+            "Value {:#x} does not fit msr bits {}..{}",
+            field,
+            lo,
+            lo + len
+        );
+        return Err(Error::non_sequitor(s));
+    }
+    Ok((v & !(max << lo)) | (field << lo))
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HwpCapabilities {
+    pub highest_perf: u8,
+    pub guaranteed_perf: u8,
+    pub most_efficient_perf: u8,
+    pub lowest_perf: u8,
+}
+
+impl From<u64> for HwpCapabilities {
+    fn from(v: u64) -> Self {
+        Self {
+            highest_perf: bits(v, 0, 8) as u8,
+            guaranteed_perf: bits(v, 8, 8) as u8,
+            most_efficient_perf: bits(v, 16, 8) as u8,
+            lowest_perf: bits(v, 24, 8) as u8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct HwpRequest {
+    pub min_perf: u8,
+    pub max_perf: u8,
+    pub desired_perf: u8,
+    pub energy_perf_preference: u8,
+    pub activity_window: u16,
+    pub package_control: bool,
+}
+
+impl HwpRequest {
+    // Fields are merged into `raw` so that reserved and valid bits survive a
+    // read-modify-write.
+    pub fn encode(&self, raw: u64) -> Result<u64> {
+        let v = set_bits(raw, 0, 8, self.min_perf as u64)?;
+        let v = set_bits(v, 8, 8, self.max_perf as u64)?;
+        let v = set_bits(v, 16, 8, self.desired_perf as u64)?;
+        let v = set_bits(v, 24, 8, self.energy_perf_preference as u64)?;
+        let v = set_bits(v, 32, 10, self.activity_window as u64)?;
+        set_bits(v, 42, 1, self.package_control as u64)
+    }
+}
+
+impl From<u64> for HwpRequest {
+    fn from(v: u64) -> Self {
+        Self {
+            min_perf: bits(v, 0, 8) as u8,
+            max_perf: bits(v, 8, 8) as u8,
+            desired_perf: bits(v, 16, 8) as u8,
+            energy_perf_preference: bits(v, 24, 8) as u8,
+            activity_window: bits(v, 32, 10) as u16,
+            package_control: bits(v, 42, 1) == 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct RaplPowerUnit {
+    pub power_unit: u8,
+    pub energy_unit: u8,
+    pub time_unit: u8,
+}
+
+impl RaplPowerUnit {
+    pub fn power_uw(&self, v: u64) -> u64 {
+        let v = (v as u128 * 1_000_000) >> self.power_unit;
+        v.min(u64::MAX as u128) as u64
+    }
+
+    pub fn to_power(&self, uw: u64) -> u64 {
+        let v = ((uw as u128) << self.power_unit) / 1_000_000;
+        v.min(u64::MAX as u128) as u64
+    }
+
+    pub fn energy_uj(&self, v: u64) -> u64 {
+        let v = (v as u128 * 1_000_000) >> self.energy_unit;
+        v.min(u64::MAX as u128) as u64
+    }
+
+    // The time window is encoded as 2^y * (1 + z/4) time units.
+    pub fn time_window_us(&self, v: u64) -> u64 {
+        let (y, z) = (bits(v, 0, 5), bits(v, 5, 2));
+        (((1u64 << y) * (4 + z) * 1_000_000) >> self.time_unit) / 4
+    }
+
+    pub fn to_time_window(&self, us: u64) -> u64 {
+        let mut r = (0, u64::MAX);
+        for y in 0..32 {
+            for z in 0..4 {
+                let v = y | (z << 5);
+                let diff = self.time_window_us(v).abs_diff(us);
+                if diff < r.1 {
+                    r = (v, diff);
+                }
+            }
+        }
+        r.0
+    }
+}
+
+impl From<u64> for RaplPowerUnit {
+    fn from(v: u64) -> Self {
+        Self {
+            power_unit: bits(v, 0, 4) as u8,
+            energy_unit: bits(v, 8, 5) as u8,
+            time_unit: bits(v, 16, 4) as u8,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PowerLimit {
+    pub power_limit_uw: u64,
+    pub time_window_us: u64,
+    pub enabled: bool,
+    pub clamp: bool,
+}
+
+impl PowerLimit {
+    fn decode(v: u64, units: &RaplPowerUnit) -> Self {
+        Self {
+            power_limit_uw: units.power_uw(bits(v, 0, 15)),
+            enabled: bits(v, 15, 1) == 1,
+            clamp: bits(v, 16, 1) == 1,
+            time_window_us: units.time_window_us(bits(v, 17, 7)),
+        }
+    }
+
+    fn encode(&self, raw: u64, units: &RaplPowerUnit) -> Result<u64> {
+        let v = set_bits(raw, 0, 15, units.to_power(self.power_limit_uw))?;
+        let v = set_bits(v, 15, 1, self.enabled as u64)?;
+        let v = set_bits(v, 16, 1, self.clamp as u64)?;
+        set_bits(v, 17, 7, units.to_time_window(self.time_window_us))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct PkgPowerLimit {
+    pub long_term: PowerLimit,
+    pub short_term: PowerLimit,
+    pub locked: bool,
+}
+
+impl PkgPowerLimit {
+    pub fn decode(v: u64, units: &RaplPowerUnit) -> Self {
+        Self {
+            long_term: PowerLimit::decode(bits(v, 0, 32), units),
+            short_term: PowerLimit::decode(bits(v, 32, 31), units),
+            locked: bits(v, 63, 1) == 1,
+        }
+    }
+
+    // The lock bit is never set from here; once set it persists until reset.
+    pub fn encode(&self, raw: u64, units: &RaplPowerUnit) -> Result<u64> {
+        let long_term = self.long_term.encode(bits(raw, 0, 32), units)?;
+        let short_term = self.short_term.encode(bits(raw, 32, 31), units)?;
+        let v = set_bits(raw, 0, 32, long_term)?;
+        set_bits(v, 32, 31, short_term)
+    }
+}
+
+pub(crate) async fn read_msr(path: &Path, reg: u64) -> Result<u64> {
+    let mut buf = [0u8; 8];
+    let r = async {
+        let mut f = OpenOptions::new().read(true).open(path).await?;
+        f.seek(SeekFrom::Start(reg)).await?;
+        f.read_exact(&mut buf).await?;
+        Ok(())
+    };
+    r.await.map_err(|e| Error::sysfs_read(e, path))?;
+    let v = u64::from_le_bytes(buf);
+    #[cfg(feature = "logging")]
+    log::trace!("OK msr r {} {:#x} {:#x}", path.display(), reg, v);
+    Ok(v)
+}
+
+pub(crate) async fn write_msr(path: &Path, reg: u64, v: u64) -> Result<()> {
+    let r = async {
+        let mut f = OpenOptions::new().write(true).open(path).await?;
+        f.seek(SeekFrom::Start(reg)).await?;
+        f.write_all(&v.to_le_bytes()).await?;
+        f.flush().await
+    };
+    r.await.map_err(|e| Error::sysfs_write(e, path))?;
+    #[cfg(feature = "logging")]
+    log::trace!("OK msr w {} {:#x} {:#x}", path.display(), reg, v);
+    Ok(())
+}
+
+pub async fn available() -> Result<bool> {
+    Ok(path::msr(0).exists())
+}
+
+pub async fn exists(id: u64) -> Result<bool> {
+    Ok(path::msr(id).exists())
+}
+
+pub fn ids() -> impl Stream<Item = Result<u64>> {
+    sysfs::read_ids(&path::root(), "")
+        .try_filter(|id| futures::future::ready(path::msr(*id).exists()))
+}
+
+// Register accessors take the device path so that `Values::with_path` can
+// point them at a regular file.
+
+pub(crate) async fn energy_perf_bias_at(path: &Path) -> Result<u64> {
+    read_msr(path, IA32_ENERGY_PERF_BIAS).await.map(|v| v & 0xf)
+}
+
+pub(crate) async fn hwp_capabilities_at(path: &Path) -> Result<HwpCapabilities> {
+    read_msr(path, IA32_HWP_CAPABILITIES).await.map(From::from)
+}
+
+pub(crate) async fn hwp_request_at(path: &Path) -> Result<HwpRequest> {
+    read_msr(path, IA32_HWP_REQUEST).await.map(From::from)
+}
+
+pub(crate) async fn rapl_power_unit_at(path: &Path) -> Result<RaplPowerUnit> {
+    read_msr(path, MSR_RAPL_POWER_UNIT).await.map(From::from)
+}
+
+pub(crate) async fn pkg_power_limit_at(path: &Path) -> Result<PkgPowerLimit> {
+    let units = rapl_power_unit_at(path).await?;
+    let v = read_msr(path, MSR_PKG_POWER_LIMIT).await?;
+    Ok(PkgPowerLimit::decode(v, &units))
+}
+
+pub(crate) async fn set_energy_perf_bias_at(path: &Path, v: u64) -> Result<()> {
+    let raw = read_msr(path, IA32_ENERGY_PERF_BIAS).await?;
+    write_msr(path, IA32_ENERGY_PERF_BIAS, set_bits(raw, 0, 4, v)?).await
+}
+
+pub(crate) async fn set_hwp_request_at(path: &Path, v: HwpRequest) -> Result<()> {
+    let raw = read_msr(path, IA32_HWP_REQUEST).await?;
+    write_msr(path, IA32_HWP_REQUEST, v.encode(raw)?).await
+}
+
+pub(crate) async fn set_pkg_power_limit_at(path: &Path, v: PkgPowerLimit) -> Result<()> {
+    let units = rapl_power_unit_at(path).await?;
+    let raw = read_msr(path, MSR_PKG_POWER_LIMIT).await?;
+    write_msr(path, MSR_PKG_POWER_LIMIT, v.encode(raw, &units)?).await
+}
+
+pub async fn read(id: u64, reg: u64) -> Result<u64> {
+    read_msr(&path::msr(id), reg).await
+}
+
+pub async fn write(id: u64, reg: u64, v: u64) -> Result<()> {
+    write_msr(&path::msr(id), reg, v).await
+}
+
+pub async fn energy_perf_bias(id: u64) -> Result<u64> {
+    energy_perf_bias_at(&path::msr(id)).await
+}
+
+pub async fn hwp_capabilities(id: u64) -> Result<HwpCapabilities> {
+    hwp_capabilities_at(&path::msr(id)).await
+}
+
+pub async fn hwp_request(id: u64) -> Result<HwpRequest> {
+    hwp_request_at(&path::msr(id)).await
+}
+
+pub async fn rapl_power_unit(id: u64) -> Result<RaplPowerUnit> {
+    rapl_power_unit_at(&path::msr(id)).await
+}
+
+pub async fn pkg_power_limit(id: u64) -> Result<PkgPowerLimit> {
+    pkg_power_limit_at(&path::msr(id)).await
+}
+
+pub async fn set_energy_perf_bias(id: u64, v: u64) -> Result<()> {
+    set_energy_perf_bias_at(&path::msr(id), v).await
+}
+
+pub async fn set_hwp_request(id: u64, v: HwpRequest) -> Result<()> {
+    set_hwp_request_at(&path::msr(id), v).await
+}
+
+pub async fn set_pkg_power_limit(id: u64, v: PkgPowerLimit) -> Result<()> {
+    set_pkg_power_limit_at(&path::msr(id), v).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Seek as _, Write as _};
+    use std::path::PathBuf;
+
+    use super::*;
+
+    // Lays out `regs` as an msr device would, 8 little-endian bytes at each
+    // register offset. Unlike the device, registers less than 8 apart overlap
+    // in a regular file, so those need separate files.
+    fn msr_file(name: &str, regs: &[(u64, u64)]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("syx-msr-{}-{}", std::process::id(), name));
+        let mut f = std::fs::File::create(&path).unwrap();
+        for (reg, v) in regs {
+            f.seek(std::io::SeekFrom::Start(*reg)).unwrap();
+            f.write_all(&v.to_le_bytes()).unwrap();
+        }
+        path
+    }
+
+    // 1/8 W power, 1/16384 J energy and 1/1024 s time units.
+    const UNITS: u64 = 0x000a_0e03;
+    // Long term 35 W over 28 s, enabled and clamped. Short term 52.5 W over
+    // 1953 us, enabled. Unlocked.
+    const LIMIT: u64 = 0x0002_81a4_00dd_8118;
+
+    #[tokio::test]
+    async fn hwp_capabilities() {
+        let path = msr_file("hwp-capabilities", &[(IA32_HWP_CAPABILITIES, 0x0108_2a30)]);
+        let v = Values::with_path(0, &path);
+        assert_eq!(
+            v.hwp_capabilities().await.unwrap(),
+            HwpCapabilities {
+                highest_perf: 0x30,
+                guaranteed_perf: 0x2a,
+                most_efficient_perf: 0x08,
+                lowest_perf: 0x01,
+            }
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn hwp_request() {
+        let path = msr_file(
+            "hwp-request",
+            &[
+                (IA32_ENERGY_PERF_BIAS, 0xf6),
+                (IA32_HWP_REQUEST, 0x0000_0580_8030_2a08),
+            ],
+        );
+        let v = Values::with_path(0, &path);
+        assert_eq!(v.energy_perf_bias().await.unwrap(), 6);
+        let req = HwpRequest {
+            min_perf: 0x08,
+            max_perf: 0x2a,
+            desired_perf: 0x30,
+            energy_perf_preference: 0x80,
+            activity_window: 0x180,
+            package_control: true,
+        };
+        assert_eq!(v.hwp_request().await.unwrap(), req);
+        let req = HwpRequest {
+            energy_perf_preference: 0xc0,
+            ..req
+        };
+        v.set_hwp_request(req).await.unwrap();
+        assert_eq!(v.hwp_request().await.unwrap(), req);
+        v.set_energy_perf_bias(15).await.unwrap();
+        assert_eq!(v.read(IA32_ENERGY_PERF_BIAS).await.unwrap(), 0xff);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn pkg_power_limit() {
+        let path = msr_file(
+            "pkg-power-limit",
+            &[(MSR_RAPL_POWER_UNIT, UNITS), (MSR_PKG_POWER_LIMIT, LIMIT)],
+        );
+        let v = Values::with_path(0, &path);
+        assert_eq!(
+            v.rapl_power_unit().await.unwrap(),
+            RaplPowerUnit {
+                power_unit: 3,
+                energy_unit: 14,
+                time_unit: 10,
+            }
+        );
+        let limit = PkgPowerLimit {
+            long_term: PowerLimit {
+                power_limit_uw: 35_000_000,
+                time_window_us: 28_000_000,
+                enabled: true,
+                clamp: true,
+            },
+            short_term: PowerLimit {
+                power_limit_uw: 52_500_000,
+                time_window_us: 1_953,
+                enabled: true,
+                clamp: false,
+            },
+            locked: false,
+        };
+        assert_eq!(v.pkg_power_limit().await.unwrap(), limit);
+        v.set_pkg_power_limit(limit).await.unwrap();
+        assert_eq!(v.read(MSR_PKG_POWER_LIMIT).await.unwrap(), LIMIT);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn large_counters() {
+        let units = RaplPowerUnit {
+            power_unit: 3,
+            energy_unit: 14,
+            time_unit: 10,
+        };
+        assert_eq!(units.energy_uj(u32::MAX as u64), 262_143_999_938);
+        // The multiplication overflows u64 before the shift brings it back.
+        assert_eq!(units.energy_uj(1 << 50), 68_719_476_736_000_000);
+        assert_eq!(units.energy_uj(u64::MAX), u64::MAX);
+        assert_eq!(units.power_uw(u64::MAX), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn out_of_range() {
+        let path = msr_file(
+            "out-of-range",
+            &[
+                (IA32_ENERGY_PERF_BIAS, 0x6),
+                (IA32_HWP_REQUEST, 0),
+                (MSR_RAPL_POWER_UNIT, UNITS),
+                (MSR_PKG_POWER_LIMIT, LIMIT),
+            ],
+        );
+        let v = Values::with_path(0, &path);
+        assert!(v.set_energy_perf_bias(16).await.is_err());
+        let req = HwpRequest {
+            activity_window: 0x400,
+            ..Default::default()
+        };
+        assert!(v.set_hwp_request(req).await.is_err());
+        let mut limit = v.pkg_power_limit().await.unwrap();
+        limit.long_term.power_limit_uw = 4_096_000_000;
+        assert!(v.set_pkg_power_limit(limit).await.is_err());
+        assert_eq!(v.read(IA32_ENERGY_PERF_BIAS).await.unwrap(), 0x6);
+        assert_eq!(v.read(IA32_HWP_REQUEST).await.unwrap(), 0);
+        assert_eq!(v.read(MSR_PKG_POWER_LIMIT).await.unwrap(), LIMIT);
+        std::fs::remove_file(path).unwrap();
+    }
+}
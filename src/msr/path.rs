@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+pub(crate) fn root() -> PathBuf {
+    PathBuf::from("/dev/cpu")
+}
+
+pub(crate) fn msr(id: u64) -> PathBuf {
+    root().join(format!("{}/msr", id))
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::msr::{self, path, HwpCapabilities, HwpRequest, PkgPowerLimit, RaplPowerUnit};
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values {
+    id: u64,
+    path: PathBuf,
+}
+
+impl Values {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        msr::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        msr::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        msr::ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        msr::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self::with_path(id, path::msr(id))
+    }
+
+    // Any file laid out like an msr device (8 bytes at each register offset)
+    // may stand in for `/dev/cpu/N/msr`.
+    pub fn with_path(id: u64, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self { id, path }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub async fn read(&self, reg: u64) -> Result<u64> {
+        msr::read_msr(&self.path, reg).await
+    }
+
+    pub async fn write(&self, reg: u64, v: u64) -> Result<()> {
+        msr::write_msr(&self.path, reg, v).await
+    }
+
+    pub async fn energy_perf_bias(&self) -> Result<u64> {
+        msr::energy_perf_bias_at(&self.path).await
+    }
+
+    pub async fn hwp_capabilities(&self) -> Result<HwpCapabilities> {
+        msr::hwp_capabilities_at(&self.path).await
+    }
+
+    pub async fn hwp_request(&self) -> Result<HwpRequest> {
+        msr::hwp_request_at(&self.path).await
+    }
+
+    pub async fn rapl_power_unit(&self) -> Result<RaplPowerUnit> {
+        msr::rapl_power_unit_at(&self.path).await
+    }
+
+    pub async fn pkg_power_limit(&self) -> Result<PkgPowerLimit> {
+        msr::pkg_power_limit_at(&self.path).await
+    }
+
+    pub async fn set_energy_perf_bias(&self, v: u64) -> Result<()> {
+        msr::set_energy_perf_bias_at(&self.path, v).await
+    }
+
+    pub async fn set_hwp_request(&self, v: HwpRequest) -> Result<()> {
+        msr::set_hwp_request_at(&self.path, v).await
+    }
+
+    pub async fn set_pkg_power_limit(&self, v: PkgPowerLimit) -> Result<()> {
+        msr::set_pkg_power_limit_at(&self.path, v).await
+    }
+}
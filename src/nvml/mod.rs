@@ -162,6 +162,11 @@ pub async fn power(id: u64) -> Result<u32> {
     read_device(id, "power", |d| d.power_usage()).await
 }
 
+// Millijoules since the driver was last reloaded.
+pub async fn total_energy(id: u64) -> Result<u64> {
+    read_device(id, "total_energy", |d| d.total_energy_consumption()).await
+}
+
 pub async fn power_limit(id: u64) -> Result<u32> {
     read_device(id, "power_limit", |d| d.enforced_power_limit()).await
 }
@@ -80,6 +80,10 @@ impl Values {
         nvml::power(self.id)
     }
 
+    pub fn total_energy(&self) -> impl Future<Output = Result<u64>> {
+        nvml::total_energy(self.id)
+    }
+
     pub fn power_limit(&self) -> impl Future<Output = Result<u32>> {
         nvml::power_limit(self.id)
     }
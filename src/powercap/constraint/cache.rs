@@ -0,0 +1,164 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::powercap::constraint::{self, Id, Values};
+use crate::powercap::zone;
+use crate::util::cell::Cell;
+use crate::Result;
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    id: Id,
+    enabled: Cell<bool>,
+    name: Cell<String>,
+    max_power_uw: Cell<u64>,
+    max_time_window_us: Cell<u64>,
+    min_power_uw: Cell<u64>,
+    min_time_window_us: Cell<u64>,
+    power_limit_uw: Cell<u64>,
+    time_window_us: Cell<u64>,
+}
+
+impl Cache {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        constraint::available()
+    }
+
+    pub fn exists(id: Id) -> impl Future<Output = Result<bool>> {
+        constraint::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<Id>> {
+        constraint::ids()
+    }
+
+    pub fn ids_for_zone(zone: impl Into<zone::Id>) -> impl Stream<Item = Result<Id>> {
+        constraint::ids_for_zone(zone)
+    }
+
+    pub fn id_for_name<Z, S>(zone: Z, name: S) -> impl Future<Output = Result<Option<Id>>>
+    where
+        Z: Into<zone::Id>,
+        S: Into<String>,
+    {
+        constraint::id_for_name(zone, name)
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        constraint::ids().map_ok(Self::new)
+    }
+
+    pub fn all_for_zone(zone: impl Into<zone::Id>) -> impl Stream<Item = Result<Self>> {
+        constraint::ids_for_zone(zone).map_ok(Self::new)
+    }
+
+    pub async fn for_name(zone: impl Into<zone::Id>, name: &str) -> Result<Option<Self>> {
+        Ok(constraint::id_for_name(zone, name).await?.map(Self::new))
+    }
+
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            enabled: Cell::default(),
+            name: Cell::default(),
+            max_power_uw: Cell::default(),
+            max_time_window_us: Cell::default(),
+            min_power_uw: Cell::default(),
+            min_time_window_us: Cell::default(),
+            power_limit_uw: Cell::default(),
+            time_window_us: Cell::default(),
+        }
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(
+            self.enabled.clear(),
+            self.name.clear(),
+            self.max_power_uw.clear(),
+            self.max_time_window_us.clear(),
+            self.min_power_uw.clear(),
+            self.min_time_window_us.clear(),
+            self.power_limit_uw.clear(),
+            self.time_window_us.clear(),
+        );
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub async fn enabled(&self) -> Result<bool> {
+        self.enabled
+            .get_or_load(constraint::enabled(self.id.clone()))
+            .await
+    }
+
+    pub async fn name(&self) -> Result<String> {
+        self.name
+            .get_or_load(constraint::name(self.id.clone()))
+            .await
+    }
+
+    pub async fn max_power_uw(&self) -> Result<u64> {
+        self.max_power_uw
+            .get_or_load(constraint::max_power_uw(self.id.clone()))
+            .await
+    }
+
+    pub async fn max_time_window_us(&self) -> Result<u64> {
+        self.max_time_window_us
+            .get_or_load(constraint::max_time_window_us(self.id.clone()))
+            .await
+    }
+
+    pub async fn min_power_uw(&self) -> Result<u64> {
+        self.min_power_uw
+            .get_or_load(constraint::min_power_uw(self.id.clone()))
+            .await
+    }
+
+    pub async fn min_time_window_us(&self) -> Result<u64> {
+        self.min_time_window_us
+            .get_or_load(constraint::min_time_window_us(self.id.clone()))
+            .await
+    }
+
+    pub async fn power_limit_uw(&self) -> Result<u64> {
+        self.power_limit_uw
+            .get_or_load(constraint::power_limit_uw(self.id.clone()))
+            .await
+    }
+
+    pub async fn time_window_us(&self) -> Result<u64> {
+        self.time_window_us
+            .get_or_load(constraint::time_window_us(self.id.clone()))
+            .await
+    }
+
+    pub async fn set_enabled(&self, v: bool) -> Result<()> {
+        let f = constraint::set_enabled(self.id.clone(), v);
+        self.enabled.clear_if_ok(f).await
+    }
+
+    pub async fn set_power_limit_uw(&self, v: u64) -> Result<()> {
+        let f = constraint::set_power_limit_uw(self.id.clone(), v);
+        self.power_limit_uw.clear_if_ok(f).await
+    }
+
+    pub async fn set_time_window_us(&self, v: u64) -> Result<()> {
+        let f = constraint::set_time_window_us(self.id.clone(), v);
+        self.time_window_us.clear_if_ok(f).await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(v: Values) -> Self {
+        Self::new(v.id().clone())
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(v: &Values) -> Self {
+        Self::new(v.id().clone())
+    }
+}
@@ -0,0 +1,161 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub(crate) mod path;
+mod values;
+
+use async_stream::stream;
+use futures::pin_mut;
+use futures::stream::{Stream, TryStreamExt as _};
+
+pub use crate::powercap::available;
+use crate::powercap::check_locked;
+#[cfg(feature = "cache")]
+pub use crate::powercap::constraint::cache::Cache;
+pub use crate::powercap::constraint::values::Values;
+use crate::powercap::zone::{self, Id as ZoneId};
+use crate::util::sysfs;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id {
+    zone: ZoneId,
+    index: u64,
+}
+
+impl Id {
+    pub fn new(zone: impl Into<ZoneId>, index: u64) -> Self {
+        Self {
+            zone: zone.into(),
+            index,
+        }
+    }
+
+    pub fn zone(&self) -> &ZoneId {
+        &self.zone
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn is_in(&self, zone: &ZoneId) -> bool {
+        &self.zone == zone
+    }
+}
+
+impl From<(ZoneId, u64)> for Id {
+    fn from(v: (ZoneId, u64)) -> Self {
+        Self::new(v.0, v.1)
+    }
+}
+
+impl From<Id> for ZoneId {
+    fn from(v: Id) -> Self {
+        v.zone
+    }
+}
+
+pub fn ids() -> impl Stream<Item = Result<Id>> {
+    zone::ids().map_ok(ids_for_zone).try_flatten()
+}
+
+pub fn ids_for_zone(zone: impl Into<ZoneId>) -> impl Stream<Item = Result<Id>> {
+    let zone = zone.into();
+    stream! {
+        for c in 0.. {
+            let id = Id::new(zone.clone(), c);
+            if path::name(&id).is_file() {
+                yield Ok(id);
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+pub async fn id_for_name<Z, S>(zone: Z, name_: S) -> Result<Option<Id>>
+where
+    Z: Into<ZoneId>,
+    S: Into<String>,
+{
+    let (zone, name_) = (zone.into(), name_.into());
+    let mut r = None;
+    let s = ids_for_zone(zone);
+    pin_mut!(s);
+    while let Some(v) = s.try_next().await? {
+        if name_ == name(v.clone()).await? {
+            r = Some(v);
+            break;
+        }
+    }
+    Ok(r)
+}
+
+pub async fn exists(id: impl Into<Id>) -> Result<bool> {
+    Ok(path::name(&id.into()).is_file())
+}
+
+// Falls back to the zone's `enabled` when the constraint has no attribute of
+// its own.
+pub async fn enabled(id: impl Into<Id>) -> Result<bool> {
+    let id = id.into();
+    let path = path::enabled(&id);
+    if path.is_file() {
+        sysfs::read_bool(&path).await
+    } else {
+        zone::enabled(id.zone).await
+    }
+}
+
+pub async fn name(id: impl Into<Id>) -> Result<String> {
+    sysfs::read_string(&path::name(&id.into())).await
+}
+
+pub async fn max_power_uw(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::max_power_uw(&id.into())).await
+}
+
+pub async fn max_time_window_us(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::max_time_window_us(&id.into())).await
+}
+
+pub async fn min_power_uw(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::min_power_uw(&id.into())).await
+}
+
+pub async fn min_time_window_us(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::min_time_window_us(&id.into())).await
+}
+
+pub async fn power_limit_uw(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::power_limit_uw(&id.into())).await
+}
+
+pub async fn time_window_us(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::time_window_us(&id.into())).await
+}
+
+// Like `enabled`, writes the zone's `enabled` when the constraint has no
+// attribute of its own.
+pub async fn set_enabled(id: impl Into<Id>, v: bool) -> Result<()> {
+    let id = id.into();
+    let path = path::enabled(&id);
+    if path.is_file() {
+        let r = sysfs::write_bool(&path, v).await;
+        check_locked(r, &id.zone).await
+    } else {
+        zone::set_enabled(id.zone, v).await
+    }
+}
+
+pub async fn set_power_limit_uw(id: impl Into<Id>, v: u64) -> Result<()> {
+    let id = id.into();
+    let r = sysfs::write_u64(&path::power_limit_uw(&id), v).await;
+    check_locked(r, &id.zone).await
+}
+
+pub async fn set_time_window_us(id: impl Into<Id>, v: u64) -> Result<()> {
+    let id = id.into();
+    let r = sysfs::write_u64(&path::time_window_us(&id), v).await;
+    check_locked(r, &id.zone).await
+}
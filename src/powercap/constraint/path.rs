@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use crate::powercap::constraint::Id;
+use crate::powercap::zone::path::zone_attr;
+
+pub(crate) fn constraint_attr(id: &Id, a: &str) -> PathBuf {
+    zone_attr(&id.zone, &format!("constraint_{}_{}", id.index, a))
+}
+
+pub(crate) fn enabled(id: &Id) -> PathBuf {
+    constraint_attr(id, "enabled")
+}
+
+pub(crate) fn name(id: &Id) -> PathBuf {
+    constraint_attr(id, "name")
+}
+
+pub(crate) fn max_power_uw(id: &Id) -> PathBuf {
+    constraint_attr(id, "max_power_uw")
+}
+
+pub(crate) fn max_time_window_us(id: &Id) -> PathBuf {
+    constraint_attr(id, "max_time_window_us")
+}
+
+pub(crate) fn min_power_uw(id: &Id) -> PathBuf {
+    constraint_attr(id, "min_power_uw")
+}
+
+pub(crate) fn min_time_window_us(id: &Id) -> PathBuf {
+    constraint_attr(id, "min_time_window_us")
+}
+
+pub(crate) fn power_limit_uw(id: &Id) -> PathBuf {
+    constraint_attr(id, "power_limit_uw")
+}
+
+pub(crate) fn time_window_us(id: &Id) -> PathBuf {
+    constraint_attr(id, "time_window_us")
+}
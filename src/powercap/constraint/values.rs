@@ -0,0 +1,117 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::powercap::constraint::{self, Id};
+#[cfg(feature = "cache")]
+use crate::powercap::constraint::Cache;
+use crate::powercap::zone;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values {
+    id: Id,
+}
+
+impl Values {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        constraint::available()
+    }
+
+    pub fn exists(id: Id) -> impl Future<Output = Result<bool>> {
+        constraint::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<Id>> {
+        constraint::ids()
+    }
+
+    pub fn ids_for_zone(zone: impl Into<zone::Id>) -> impl Stream<Item = Result<Id>> {
+        constraint::ids_for_zone(zone)
+    }
+
+    pub fn id_for_name<Z, S>(zone: Z, name: S) -> impl Future<Output = Result<Option<Id>>>
+    where
+        Z: Into<zone::Id>,
+        S: Into<String>,
+    {
+        constraint::id_for_name(zone, name)
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        constraint::ids().map_ok(Self::new)
+    }
+
+    pub fn all_for_zone(zone: impl Into<zone::Id>) -> impl Stream<Item = Result<Self>> {
+        constraint::ids_for_zone(zone).map_ok(Self::new)
+    }
+
+    pub async fn for_name(zone: impl Into<zone::Id>, name: &str) -> Result<Option<Self>> {
+        Ok(constraint::id_for_name(zone, name).await?.map(Self::new))
+    }
+
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self { id: id.into() }
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn enabled(&self) -> impl Future<Output = Result<bool>> {
+        constraint::enabled(self.id.clone())
+    }
+
+    pub fn name(&self) -> impl Future<Output = Result<String>> {
+        constraint::name(self.id.clone())
+    }
+
+    pub fn max_power_uw(&self) -> impl Future<Output = Result<u64>> {
+        constraint::max_power_uw(self.id.clone())
+    }
+
+    pub fn max_time_window_us(&self) -> impl Future<Output = Result<u64>> {
+        constraint::max_time_window_us(self.id.clone())
+    }
+
+    pub fn min_power_uw(&self) -> impl Future<Output = Result<u64>> {
+        constraint::min_power_uw(self.id.clone())
+    }
+
+    pub fn min_time_window_us(&self) -> impl Future<Output = Result<u64>> {
+        constraint::min_time_window_us(self.id.clone())
+    }
+
+    pub fn power_limit_uw(&self) -> impl Future<Output = Result<u64>> {
+        constraint::power_limit_uw(self.id.clone())
+    }
+
+    pub fn time_window_us(&self) -> impl Future<Output = Result<u64>> {
+        constraint::time_window_us(self.id.clone())
+    }
+
+    pub fn set_enabled(&self, v: bool) -> impl Future<Output = Result<()>> {
+        constraint::set_enabled(self.id.clone(), v)
+    }
+
+    pub fn set_power_limit_uw(&self, v: u64) -> impl Future<Output = Result<()>> {
+        constraint::set_power_limit_uw(self.id.clone(), v)
+    }
+
+    pub fn set_time_window_us(&self, v: u64) -> impl Future<Output = Result<()>> {
+        constraint::set_time_window_us(self.id.clone(), v)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<Cache> for Values {
+    fn from(v: Cache) -> Self {
+        Self::new(v.id().clone())
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<&Cache> for Values {
+    fn from(v: &Cache) -> Self {
+        Self::new(v.id().clone())
+    }
+}
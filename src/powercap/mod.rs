@@ -0,0 +1,67 @@
+pub mod constraint;
+pub(crate) mod path;
+pub mod zone;
+
+use std::io::ErrorKind;
+use std::path::Path;
+
+use futures::stream::{Stream, TryStreamExt as _};
+
+use crate::util::sysfs;
+use crate::{Error, Op, Result};
+
+pub const DTPM: &str = "dtpm";
+pub const INTEL_RAPL: &str = "intel-rapl";
+pub const INTEL_RAPL_MMIO: &str = "intel-rapl-mmio";
+
+pub async fn available() -> Result<bool> {
+    Ok(path::root().is_dir())
+}
+
+// Control types are the entries of `/sys/class/powercap` without a zone index.
+pub fn control_types() -> impl Stream<Item = Result<String>> {
+    sysfs::read_names(&path::root()).try_filter(|v| {
+        let r = !v.contains(':') && path::enabled(v).is_file();
+        async move { r }
+    })
+}
+
+pub async fn exists(control_type: &str) -> Result<bool> {
+    Ok(path::enabled(control_type).is_file())
+}
+
+pub async fn enabled(control_type: &str) -> Result<bool> {
+    sysfs::read_bool(&path::enabled(control_type)).await
+}
+
+pub async fn set_enabled(control_type: &str, v: bool) -> Result<()> {
+    sysfs::write_bool(&path::enabled(control_type), v).await
+}
+
+async fn is_root() -> bool {
+    let s = sysfs::read_string(Path::new("/proc/self/status")).await;
+    s.ok()
+        .and_then(|s| {
+            let l = s.lines().find(|l| l.starts_with("Uid:"))?;
+            l.split_whitespace().nth(2).map(|v| v == "0")
+        })
+        .unwrap_or(false)
+}
+
+// The kernel rejects limit writes with EACCES when firmware has set the lock
+// bit, which for root can't be a permissions problem.
+pub(crate) async fn check_locked(r: Result<()>, zone: &zone::Id) -> Result<()> {
+    match r {
+        Err(Error::SysfsIo {
+            source,
+            path,
+            op: Op::Write,
+        }) if source.kind() == ErrorKind::PermissionDenied && is_root().await => {
+            let e = Error::powercap_locked(source, path, zone);
+            #[cfg(feature = "logging")]
+            log::error!("ERR {}", e);
+            Err(e)
+        },
+        r => r,
+    }
+}
@@ -0,0 +1,13 @@
+use std::path::PathBuf;
+
+pub(crate) fn root() -> PathBuf {
+    PathBuf::from("/sys/class/powercap")
+}
+
+pub(crate) fn control_type(name: &str) -> PathBuf {
+    root().join(name)
+}
+
+pub(crate) fn enabled(control_type_: &str) -> PathBuf {
+    control_type(control_type_).join("enabled")
+}
@@ -0,0 +1,122 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::powercap::zone::{self, Id, Values};
+use crate::util::cell::Cell;
+use crate::Result;
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    id: Id,
+    enabled: Cell<bool>,
+    energy_uj: Cell<u64>,
+    max_energy_range_uj: Cell<u64>,
+    max_power_range_uw: Cell<u64>,
+    name: Cell<String>,
+    power_uw: Cell<u64>,
+}
+
+impl Cache {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        zone::available()
+    }
+
+    pub fn exists(id: Id) -> impl Future<Output = Result<bool>> {
+        zone::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<Id>> {
+        zone::ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        zone::ids().map_ok(Self::new)
+    }
+
+    pub fn all_for_control_type(control_type: &str) -> impl Stream<Item = Result<Self>> {
+        zone::ids_for_control_type(control_type).map_ok(Self::new)
+    }
+
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            enabled: Cell::default(),
+            energy_uj: Cell::default(),
+            max_energy_range_uj: Cell::default(),
+            max_power_range_uw: Cell::default(),
+            name: Cell::default(),
+            power_uw: Cell::default(),
+        }
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(
+            self.enabled.clear(),
+            self.energy_uj.clear(),
+            self.max_energy_range_uj.clear(),
+            self.max_power_range_uw.clear(),
+            self.name.clear(),
+            self.power_uw.clear(),
+        );
+    }
+
+    pub fn id(&self) -> &Id {
+        &self.id
+    }
+
+    pub fn children(&self) -> impl Stream<Item = Result<Self>> {
+        zone::children(self.id.clone()).map_ok(Self::new)
+    }
+
+    pub async fn enabled(&self) -> Result<bool> {
+        self.enabled
+            .get_or_load(zone::enabled(self.id.clone()))
+            .await
+    }
+
+    pub async fn energy_uj(&self) -> Result<u64> {
+        self.energy_uj
+            .get_or_load(zone::energy_uj(self.id.clone()))
+            .await
+    }
+
+    pub async fn max_energy_range_uj(&self) -> Result<u64> {
+        self.max_energy_range_uj
+            .get_or_load(zone::max_energy_range_uj(self.id.clone()))
+            .await
+    }
+
+    pub async fn max_power_range_uw(&self) -> Result<u64> {
+        self.max_power_range_uw
+            .get_or_load(zone::max_power_range_uw(self.id.clone()))
+            .await
+    }
+
+    pub async fn name(&self) -> Result<String> {
+        self.name.get_or_load(zone::name(self.id.clone())).await
+    }
+
+    pub async fn power_uw(&self) -> Result<u64> {
+        self.power_uw
+            .get_or_load(zone::power_uw(self.id.clone()))
+            .await
+    }
+
+    pub async fn set_enabled(&self, v: bool) -> Result<()> {
+        self.enabled
+            .clear_if_ok(zone::set_enabled(self.id.clone(), v))
+            .await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(v: Values) -> Self {
+        Self::new(v.id().clone())
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(v: &Values) -> Self {
+        Self::new(v.id().clone())
+    }
+}
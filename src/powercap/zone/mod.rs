@@ -0,0 +1,205 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub(crate) mod path;
+mod values;
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
+
+pub use crate::powercap::available;
+#[cfg(feature = "cache")]
+pub use crate::powercap::zone::cache::Cache;
+pub use crate::powercap::zone::values::Values;
+use crate::util::sysfs;
+use crate::{Error, Result};
+
+// A zone is named by its control type followed by one index per nesting
+// level, e.g. `intel-rapl:0:1` or `dtpm:0:2:1`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id {
+    control_type: String,
+    indices: Vec<u64>,
+}
+
+impl Id {
+    pub fn new<S, I>(control_type: S, indices: I) -> Self
+    where
+        S: Into<String>,
+        I: IntoIterator<Item = u64>,
+    {
+        Self {
+            control_type: control_type.into(),
+            indices: indices.into_iter().collect(),
+        }
+    }
+
+    pub fn control_type(&self) -> &str {
+        &self.control_type
+    }
+
+    pub fn indices(&self) -> &[u64] {
+        &self.indices
+    }
+
+    pub fn parent(&self) -> Option<Self> {
+        match self.indices.split_last() {
+            Some((_, p)) if !p.is_empty() => Some(Self::new(&self.control_type, p.to_vec())),
+            _ => None,
+        }
+    }
+
+    pub fn child(&self, index: u64) -> Self {
+        let indices = self.indices.iter().copied().chain([index]);
+        Self::new(&self.control_type, indices)
+    }
+}
+
+impl Display for Id {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.control_type)?;
+        for i in &self.indices {
+            write!(f, ":{}", i)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Id {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut p = s.split(':');
+        let control_type = p.next().filter(|v| !v.is_empty());
+        let indices: Option<Vec<_>> = p.map(|v| v.parse::<u64>().ok()).collect();
+        match (control_type, indices) {
+            (Some(c), Some(i)) if !i.is_empty() => Ok(Self::new(c, i)),
+            _ => {
+                let s = format!("Invalid powercap zone id: {}", s);
+                Err(Error::non_sequitor(s))
+            },
+        }
+    }
+}
+
+pub fn ids() -> impl Stream<Item = Result<Id>> {
+    try_stream! {
+        let mut ids: Vec<Id> = sysfs::read_names(&crate::powercap::path::root())
+            .try_filter_map(|v| async move { Ok(v.parse::<Id>().ok()) })
+            .try_collect()
+            .await?;
+        ids.sort();
+        for id in ids {
+            yield id;
+        }
+    }
+}
+
+pub fn ids_for_control_type(control_type: &str) -> impl Stream<Item = Result<Id>> {
+    let control_type = control_type.to_string();
+    ids().try_filter(move |v| {
+        let r = v.control_type == control_type;
+        async move { r }
+    })
+}
+
+pub fn children(id: impl Into<Id>) -> impl Stream<Item = Result<Id>> {
+    let id = id.into();
+    try_stream! {
+        let prefix = format!("{}:", id);
+        let mut indices: Vec<_> = sysfs::read_ids(&path::zone(&id), &prefix)
+            .try_collect()
+            .await?;
+        indices.sort_unstable();
+        for i in indices {
+            yield id.child(i);
+        }
+    }
+}
+
+pub async fn exists(id: impl Into<Id>) -> Result<bool> {
+    Ok(path::zone(&id.into()).is_dir())
+}
+
+pub async fn enabled(id: impl Into<Id>) -> Result<bool> {
+    sysfs::read_bool(&path::enabled(&id.into())).await
+}
+
+pub async fn energy_uj(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::energy_uj(&id.into())).await
+}
+
+pub async fn max_energy_range_uj(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::max_energy_range_uj(&id.into())).await
+}
+
+pub async fn max_power_range_uw(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::max_power_range_uw(&id.into())).await
+}
+
+pub async fn name(id: impl Into<Id>) -> Result<String> {
+    sysfs::read_string(&path::name(&id.into())).await
+}
+
+pub async fn power_uw(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::power_uw(&id.into())).await
+}
+
+pub async fn set_enabled(id: impl Into<Id>, v: bool) -> Result<()> {
+    let id = id.into();
+    let r = sysfs::write_bool(&path::enabled(&id), v).await;
+    crate::powercap::check_locked(r, &id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_from_str() {
+        let r: Id = "intel-rapl:0".parse().unwrap();
+        assert_eq!(r, Id::new("intel-rapl", [0]));
+        let r: Id = "intel-rapl-mmio:0".parse().unwrap();
+        assert_eq!(r.control_type(), "intel-rapl-mmio");
+        assert_eq!(r.indices(), &[0]);
+        let r: Id = "dtpm:0:2:1".parse().unwrap();
+        assert_eq!(r, Id::new("dtpm", [0, 2, 1]));
+    }
+
+    #[test]
+    fn id_from_str_invalid() {
+        for s in [
+            "",
+            "intel-rapl",
+            ":0",
+            "intel-rapl:",
+            "intel-rapl:a",
+            "intel-rapl:0:-1",
+        ] {
+            assert!(s.parse::<Id>().is_err(), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn id_display() {
+        for s in [
+            "intel-rapl:0",
+            "intel-rapl:0:1",
+            "intel-rapl-mmio:0",
+            "dtpm:0:2:1",
+        ] {
+            assert_eq!(s.parse::<Id>().unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn id_parent_child() {
+        let id = Id::new("intel-rapl", [0]);
+        assert_eq!(id.parent(), None);
+        let child = id.child(1);
+        assert_eq!(child.to_string(), "intel-rapl:0:1");
+        assert_eq!(child.parent(), Some(id));
+    }
+}
@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use crate::powercap::path::root;
+use crate::powercap::zone::Id;
+
+pub(crate) fn zone(id: &Id) -> PathBuf {
+    root().join(id.to_string())
+}
+
+pub(crate) fn zone_attr(id: &Id, a: &str) -> PathBuf {
+    zone(id).join(a)
+}
+
+pub(crate) fn enabled(id: &Id) -> PathBuf {
+    zone_attr(id, "enabled")
+}
+
+pub(crate) fn energy_uj(id: &Id) -> PathBuf {
+    zone_attr(id, "energy_uj")
+}
+
+pub(crate) fn max_energy_range_uj(id: &Id) -> PathBuf {
+    zone_attr(id, "max_energy_range_uj")
+}
+
+pub(crate) fn max_power_range_uw(id: &Id) -> PathBuf {
+    zone_attr(id, "max_power_range_uw")
+}
+
+pub(crate) fn name(id: &Id) -> PathBuf {
+    zone_attr(id, "name")
+}
+
+pub(crate) fn power_uw(id: &Id) -> PathBuf {
+    zone_attr(id, "power_uw")
+}
@@ -32,7 +32,7 @@ where
             #[cfg(feature = "logging")]
             match &v {
                 Ok(v) => log::trace!("OK cache MISS {:?}", v),
-                Err(e) => log::trace!("ERR cache {}", e.to_string()),
+                Err(e) => log::trace!("ERR cache {}", e),
             }
             let v = v?;
             value.replace(v.clone());
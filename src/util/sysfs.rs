@@ -40,6 +40,18 @@ fn read_dir_ents(path: &Path) -> impl Stream<Item = Result<DirEntry>> {
     }
 }
 
+pub(crate) fn read_names(path: &Path) -> impl Stream<Item = Result<String>> {
+    let path = path.to_path_buf();
+    try_stream! {
+        for await ent in read_dir_ents(&path) {
+            let ent = ent?;
+            if let Some(v) = ent.file_name().to_str() {
+                yield v.to_string();
+            }
+        }
+    }
+}
+
 pub(crate) fn read_ids(path: &Path, prefix: &str) -> impl Stream<Item = Result<u64>> {
     let path = path.to_path_buf();
     let prefix = prefix.to_string();
@@ -64,11 +76,11 @@ pub(crate) fn read_indices(path: &Path) -> impl Stream<Item=Result<u64>> {
     let path = path.to_path_buf();
     try_stream! {
         let s = read_string(&path).await?;
-        let i = if s.is_empty() {
-            0..0
-        } else {
-            let p: Vec<_> = s.split('-').collect();
-            match &p[..] {
+        // e.g. `nohz_full` reads `(null)` when no cpumask is configured
+        let s = if s == "(null)" { String::new() } else { s };
+        for r in s.split(',').filter(|r| !r.is_empty()) {
+            let p: Vec<_> = r.split('-').collect();
+            let i = match &p[..] {
                 [id] => {
                     let id = id.parse::<u64>()
                         .map_err(|_| Error::sysfs_parse(&path, "indices: index", &s))?;
@@ -80,14 +92,14 @@ pub(crate) fn read_indices(path: &Path) -> impl Stream<Item=Result<u64>> {
                         .map_err(|_| Error::sysfs_parse(&path, "indices: start", &s))?;
                     let end = end
                         .parse::<u64>()
-                        .map_err(|_| Error::sysfs_parse(&path, "indices: end", s))?;
+                        .map_err(|_| Error::sysfs_parse(&path, "indices: end", &s))?;
                     start..(end+1)
                 },
-                _ => Err(Error::sysfs_parse(&path, "indices", s))?,
+                _ => Err(Error::sysfs_parse(&path, "indices", &s))?,
+            };
+            for v in i {
+                yield v;
             }
-        };
-        for v in i {
-            yield v;
         }
     }
 }
@@ -124,6 +136,18 @@ pub(crate) async fn read_string_list(path: &Path, delim: char) -> Result<Vec<Str
     })
 }
 
+pub(crate) async fn read_u64_list(path: &Path, delim: char) -> Result<Vec<u64>> {
+    read_string_list(path, delim)
+        .await?
+        .into_iter()
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            v.parse::<u64>()
+                .map_err(|_| Error::sysfs_parse(path, "u64", v))
+        })
+        .collect()
+}
+
 pub(crate) async fn read_u64(path: &Path) -> Result<u64> {
     let val = read_string(path).await?;
     val.parse::<u64>()
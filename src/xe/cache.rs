@@ -0,0 +1,122 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::util::cell::Cell;
+use crate::xe::{self, Values};
+use crate::Result;
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    id: u64,
+    act_freq_mhz: Cell<u64>,
+    cur_freq_mhz: Cell<u64>,
+    max_freq_mhz: Cell<u64>,
+    min_freq_mhz: Cell<u64>,
+    rp0_freq_mhz: Cell<u64>,
+    rpn_freq_mhz: Cell<u64>,
+}
+
+impl Cache {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        xe::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        xe::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        xe::ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        xe::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self {
+            id,
+            act_freq_mhz: Cell::default(),
+            cur_freq_mhz: Cell::default(),
+            max_freq_mhz: Cell::default(),
+            min_freq_mhz: Cell::default(),
+            rp0_freq_mhz: Cell::default(),
+            rpn_freq_mhz: Cell::default(),
+        }
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(
+            self.act_freq_mhz.clear(),
+            self.cur_freq_mhz.clear(),
+            self.max_freq_mhz.clear(),
+            self.min_freq_mhz.clear(),
+            self.rp0_freq_mhz.clear(),
+            self.rpn_freq_mhz.clear(),
+        );
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub async fn act_freq_mhz(&self) -> Result<u64> {
+        self.act_freq_mhz
+            .get_or_load(xe::act_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn cur_freq_mhz(&self) -> Result<u64> {
+        self.cur_freq_mhz
+            .get_or_load(xe::cur_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn max_freq_mhz(&self) -> Result<u64> {
+        self.max_freq_mhz
+            .get_or_load(xe::max_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn min_freq_mhz(&self) -> Result<u64> {
+        self.min_freq_mhz
+            .get_or_load(xe::min_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn rp0_freq_mhz(&self) -> Result<u64> {
+        self.rp0_freq_mhz
+            .get_or_load(xe::rp0_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn rpn_freq_mhz(&self) -> Result<u64> {
+        self.rpn_freq_mhz
+            .get_or_load(xe::rpn_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn set_max_freq_mhz(&self, v: u64) -> Result<()> {
+        self.max_freq_mhz
+            .clear_if_ok(xe::set_max_freq_mhz(self.id, v))
+            .await
+    }
+
+    pub async fn set_min_freq_mhz(&self, v: u64) -> Result<()> {
+        self.min_freq_mhz
+            .clear_if_ok(xe::set_min_freq_mhz(self.id, v))
+            .await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(v: Values) -> Self {
+        Self::new(v.id())
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(v: &Values) -> Self {
+        Self::new(v.id())
+    }
+}
@@ -0,0 +1,152 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::util::cell::Cell;
+use crate::xe::gt::{self, Id, Values};
+use crate::Result;
+
+#[derive(Clone, Debug)]
+pub struct Cache {
+    id: Id,
+    act_freq_mhz: Cell<u64>,
+    cur_freq_mhz: Cell<u64>,
+    max_freq_mhz: Cell<u64>,
+    min_freq_mhz: Cell<u64>,
+    rp0_freq_mhz: Cell<u64>,
+    rpn_freq_mhz: Cell<u64>,
+    throttle_status: Cell<bool>,
+    throttle_reasons: Cell<Vec<String>>,
+}
+
+impl Cache {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        gt::available()
+    }
+
+    pub fn exists(id: Id) -> impl Future<Output = Result<bool>> {
+        gt::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<Id>> {
+        gt::ids()
+    }
+
+    pub fn ids_for_card(card: u64) -> impl Stream<Item = Result<Id>> {
+        gt::ids_for_card(card)
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        gt::ids().map_ok(Self::new)
+    }
+
+    pub fn all_for_card(card: u64) -> impl Stream<Item = Result<Self>> {
+        gt::ids_for_card(card).map_ok(Self::new)
+    }
+
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self {
+            id: id.into(),
+            act_freq_mhz: Cell::default(),
+            cur_freq_mhz: Cell::default(),
+            max_freq_mhz: Cell::default(),
+            min_freq_mhz: Cell::default(),
+            rp0_freq_mhz: Cell::default(),
+            rpn_freq_mhz: Cell::default(),
+            throttle_status: Cell::default(),
+            throttle_reasons: Cell::default(),
+        }
+    }
+
+    pub async fn clear(&self) {
+        tokio::join!(
+            self.act_freq_mhz.clear(),
+            self.cur_freq_mhz.clear(),
+            self.max_freq_mhz.clear(),
+            self.min_freq_mhz.clear(),
+            self.rp0_freq_mhz.clear(),
+            self.rpn_freq_mhz.clear(),
+            self.throttle_status.clear(),
+            self.throttle_reasons.clear(),
+        );
+    }
+
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    pub async fn act_freq_mhz(&self) -> Result<u64> {
+        self.act_freq_mhz
+            .get_or_load(gt::act_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn cur_freq_mhz(&self) -> Result<u64> {
+        self.cur_freq_mhz
+            .get_or_load(gt::cur_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn max_freq_mhz(&self) -> Result<u64> {
+        self.max_freq_mhz
+            .get_or_load(gt::max_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn min_freq_mhz(&self) -> Result<u64> {
+        self.min_freq_mhz
+            .get_or_load(gt::min_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn rp0_freq_mhz(&self) -> Result<u64> {
+        self.rp0_freq_mhz
+            .get_or_load(gt::rp0_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn rpn_freq_mhz(&self) -> Result<u64> {
+        self.rpn_freq_mhz
+            .get_or_load(gt::rpn_freq_mhz(self.id))
+            .await
+    }
+
+    pub async fn throttle_status(&self) -> Result<bool> {
+        self.throttle_status
+            .get_or_load(gt::throttle_status(self.id))
+            .await
+    }
+
+    pub async fn throttle_reason(&self, reason: &str) -> Result<bool> {
+        gt::throttle_reason(self.id, reason).await
+    }
+
+    pub async fn throttle_reasons(&self) -> Result<Vec<String>> {
+        self.throttle_reasons
+            .get_or_load(gt::throttle_reasons(self.id))
+            .await
+    }
+
+    pub async fn set_max_freq_mhz(&self, v: u64) -> Result<()> {
+        self.max_freq_mhz
+            .clear_if_ok(gt::set_max_freq_mhz(self.id, v))
+            .await
+    }
+
+    pub async fn set_min_freq_mhz(&self, v: u64) -> Result<()> {
+        self.min_freq_mhz
+            .clear_if_ok(gt::set_min_freq_mhz(self.id, v))
+            .await
+    }
+}
+
+impl From<Values> for Cache {
+    fn from(v: Values) -> Self {
+        Self::new(v.id())
+    }
+}
+
+impl From<&Values> for Cache {
+    fn from(v: &Values) -> Self {
+        Self::new(v.id())
+    }
+}
@@ -0,0 +1,136 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub(crate) mod path;
+mod values;
+
+use async_stream::try_stream;
+use futures::stream::{Stream, TryStreamExt as _};
+
+use crate::util::sysfs;
+pub use crate::xe::available;
+#[cfg(feature = "cache")]
+pub use crate::xe::gt::cache::Cache;
+pub use crate::xe::gt::values::Values;
+use crate::{drm, xe, Result};
+
+// Gt numbers are unique per card rather than per tile, e.g. a card with a
+// separate media gt has `tile0/gt0` and `tile0/gt1`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Id {
+    card: u64,
+    tile: u64,
+    gt: u64,
+}
+
+impl Id {
+    pub fn new(card: u64, tile: u64, gt: u64) -> Self {
+        Self { card, tile, gt }
+    }
+
+    pub fn card(&self) -> u64 {
+        self.card
+    }
+
+    pub fn tile(&self) -> u64 {
+        self.tile
+    }
+
+    pub fn gt(&self) -> u64 {
+        self.gt
+    }
+}
+
+impl From<(u64, u64, u64)> for Id {
+    fn from(v: (u64, u64, u64)) -> Self {
+        Self::new(v.0, v.1, v.2)
+    }
+}
+
+impl From<Id> for (u64, u64, u64) {
+    fn from(v: Id) -> Self {
+        (v.card, v.tile, v.gt)
+    }
+}
+
+pub fn ids() -> impl Stream<Item = Result<Id>> {
+    xe::ids().map_ok(ids_for_card).try_flatten()
+}
+
+pub fn ids_for_card(card: u64) -> impl Stream<Item = Result<Id>> {
+    try_stream! {
+        let mut tiles: Vec<_> = sysfs::read_ids(&drm::path::device(card), "tile")
+            .try_collect()
+            .await?;
+        tiles.sort_unstable();
+        for tile in tiles {
+            let mut gts: Vec<_> = sysfs::read_ids(&xe::path::tile(card, tile), "gt")
+                .try_collect()
+                .await?;
+            gts.sort_unstable();
+            for gt in gts {
+                yield Id::new(card, tile, gt);
+            }
+        }
+    }
+}
+
+pub async fn exists(id: impl Into<Id>) -> Result<bool> {
+    Ok(path::freq(id.into()).is_dir())
+}
+
+pub async fn act_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::act_freq_mhz(id.into())).await
+}
+
+pub async fn cur_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::cur_freq_mhz(id.into())).await
+}
+
+pub async fn max_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::max_freq_mhz(id.into())).await
+}
+
+pub async fn min_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::min_freq_mhz(id.into())).await
+}
+
+pub async fn rp0_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::rp0_freq_mhz(id.into())).await
+}
+
+pub async fn rpn_freq_mhz(id: impl Into<Id>) -> Result<u64> {
+    sysfs::read_u64(&path::rpn_freq_mhz(id.into())).await
+}
+
+pub async fn throttle_status(id: impl Into<Id>) -> Result<bool> {
+    sysfs::read_bool(&path::throttle_status(id.into())).await
+}
+
+pub async fn throttle_reason(id: impl Into<Id>, reason: &str) -> Result<bool> {
+    sysfs::read_bool(&path::throttle_reason(id.into(), reason)).await
+}
+
+// Names of the asserted `throttle/reason_*` files.
+pub async fn throttle_reasons(id: impl Into<Id>) -> Result<Vec<String>> {
+    let id = id.into();
+    let mut names: Vec<_> = sysfs::read_names(&path::throttle(id))
+        .try_filter_map(|v| async move { Ok(v.strip_prefix("reason_").map(String::from)) })
+        .try_collect()
+        .await?;
+    names.sort();
+    let mut r = vec![];
+    for v in names {
+        if throttle_reason(id, &v).await? {
+            r.push(v);
+        }
+    }
+    Ok(r)
+}
+
+pub async fn set_max_freq_mhz(id: impl Into<Id>, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::max_freq_mhz(id.into()), v).await
+}
+
+pub async fn set_min_freq_mhz(id: impl Into<Id>, v: u64) -> Result<()> {
+    sysfs::write_u64(&path::min_freq_mhz(id.into()), v).await
+}
@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use crate::xe::gt::Id;
+use crate::xe::path::tile;
+
+pub(crate) fn gt(id: Id) -> PathBuf {
+    tile(id.card, id.tile).join(format!("gt{}", id.gt))
+}
+
+pub(crate) fn freq(id: Id) -> PathBuf {
+    gt(id).join("freq0")
+}
+
+pub(crate) fn freq_attr(id: Id, a: &str) -> PathBuf {
+    freq(id).join(a)
+}
+
+pub(crate) fn act_freq_mhz(id: Id) -> PathBuf {
+    freq_attr(id, "act_freq")
+}
+
+pub(crate) fn cur_freq_mhz(id: Id) -> PathBuf {
+    freq_attr(id, "cur_freq")
+}
+
+pub(crate) fn max_freq_mhz(id: Id) -> PathBuf {
+    freq_attr(id, "max_freq")
+}
+
+pub(crate) fn min_freq_mhz(id: Id) -> PathBuf {
+    freq_attr(id, "min_freq")
+}
+
+pub(crate) fn rp0_freq_mhz(id: Id) -> PathBuf {
+    freq_attr(id, "rp0_freq")
+}
+
+pub(crate) fn rpn_freq_mhz(id: Id) -> PathBuf {
+    freq_attr(id, "rpn_freq")
+}
+
+pub(crate) fn throttle(id: Id) -> PathBuf {
+    freq_attr(id, "throttle")
+}
+
+pub(crate) fn throttle_status(id: Id) -> PathBuf {
+    throttle(id).join("status")
+}
+
+pub(crate) fn throttle_reason(id: Id, reason: &str) -> PathBuf {
+    throttle(id).join(format!("reason_{}", reason))
+}
@@ -0,0 +1,104 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::xe::gt::{self, Id};
+#[cfg(feature = "cache")]
+use crate::xe::gt::Cache;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values {
+    id: Id,
+}
+
+impl Values {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        gt::available()
+    }
+
+    pub fn exists(id: Id) -> impl Future<Output = Result<bool>> {
+        gt::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<Id>> {
+        gt::ids()
+    }
+
+    pub fn ids_for_card(card: u64) -> impl Stream<Item = Result<Id>> {
+        gt::ids_for_card(card)
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        gt::ids().map_ok(Self::new)
+    }
+
+    pub fn all_for_card(card: u64) -> impl Stream<Item = Result<Self>> {
+        gt::ids_for_card(card).map_ok(Self::new)
+    }
+
+    pub fn new(id: impl Into<Id>) -> Self {
+        Self { id: id.into() }
+    }
+
+    pub fn id(&self) -> Id {
+        self.id
+    }
+
+    pub fn act_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        gt::act_freq_mhz(self.id)
+    }
+
+    pub fn cur_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        gt::cur_freq_mhz(self.id)
+    }
+
+    pub fn max_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        gt::max_freq_mhz(self.id)
+    }
+
+    pub fn min_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        gt::min_freq_mhz(self.id)
+    }
+
+    pub fn rp0_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        gt::rp0_freq_mhz(self.id)
+    }
+
+    pub fn rpn_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        gt::rpn_freq_mhz(self.id)
+    }
+
+    pub fn throttle_status(&self) -> impl Future<Output = Result<bool>> {
+        gt::throttle_status(self.id)
+    }
+
+    pub async fn throttle_reason(&self, reason: &str) -> Result<bool> {
+        gt::throttle_reason(self.id, reason).await
+    }
+
+    pub fn throttle_reasons(&self) -> impl Future<Output = Result<Vec<String>>> {
+        gt::throttle_reasons(self.id)
+    }
+
+    pub fn set_max_freq_mhz(&self, v: u64) -> impl Future<Output = Result<()>> {
+        gt::set_max_freq_mhz(self.id, v)
+    }
+
+    pub fn set_min_freq_mhz(&self, v: u64) -> impl Future<Output = Result<()>> {
+        gt::set_min_freq_mhz(self.id, v)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<Cache> for Values {
+    fn from(v: Cache) -> Self {
+        Self::new(v.id())
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<&Cache> for Values {
+    fn from(v: &Cache) -> Self {
+        Self::new(v.id())
+    }
+}
@@ -0,0 +1,95 @@
+#[cfg(feature = "cache")]
+mod cache;
+pub mod gt;
+pub(crate) mod path;
+mod values;
+
+use futures::future::try_join_all;
+use futures::pin_mut;
+use futures::stream::{Stream, TryStreamExt as _};
+
+#[cfg(feature = "cache")]
+pub use crate::xe::cache::Cache;
+pub use crate::xe::values::Values;
+use crate::{drm, Error, Result};
+
+pub async fn available() -> Result<bool> {
+    Ok(path::module().is_dir())
+}
+
+pub async fn exists(id: u64) -> Result<bool> {
+    let r = if drm::exists(id).await? {
+        "xe" == drm::driver(id).await?.as_str()
+    } else {
+        false
+    };
+    Ok(r)
+}
+
+pub fn ids() -> impl Stream<Item = Result<u64>> {
+    drm::ids_for_driver("xe")
+}
+
+// Card attributes are read from the card's first gt and written to all of
+// its gts, like i915's card-level `gt_*` attributes.
+async fn first_gt(id: u64) -> Result<gt::Id> {
+    let s = gt::ids_for_card(id);
+    pin_mut!(s);
+    match s.try_next().await? {
+        Some(v) => Ok(v),
+        None => {
+            let s = format!("No gt for xe card {}", id);
+            Err(Error::non_sequitor(s))
+        },
+    }
+}
+
+async fn gts(id: u64) -> Result<Vec<gt::Id>> {
+    gt::ids_for_card(id).try_collect().await
+}
+
+pub async fn act_freq_mhz(id: u64) -> Result<u64> {
+    gt::act_freq_mhz(first_gt(id).await?).await
+}
+
+pub async fn cur_freq_mhz(id: u64) -> Result<u64> {
+    gt::cur_freq_mhz(first_gt(id).await?).await
+}
+
+pub async fn max_freq_mhz(id: u64) -> Result<u64> {
+    gt::max_freq_mhz(first_gt(id).await?).await
+}
+
+pub async fn min_freq_mhz(id: u64) -> Result<u64> {
+    gt::min_freq_mhz(first_gt(id).await?).await
+}
+
+pub async fn rp0_freq_mhz(id: u64) -> Result<u64> {
+    gt::rp0_freq_mhz(first_gt(id).await?).await
+}
+
+pub async fn rpn_freq_mhz(id: u64) -> Result<u64> {
+    gt::rpn_freq_mhz(first_gt(id).await?).await
+}
+
+pub async fn set_max_freq_mhz(id: u64, v: u64) -> Result<()> {
+    try_join_all(
+        gts(id)
+            .await?
+            .into_iter()
+            .map(|g| gt::set_max_freq_mhz(g, v)),
+    )
+    .await?;
+    Ok(())
+}
+
+pub async fn set_min_freq_mhz(id: u64, v: u64) -> Result<()> {
+    try_join_all(
+        gts(id)
+            .await?
+            .into_iter()
+            .map(|g| gt::set_min_freq_mhz(g, v)),
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+use crate::drm::path::device_attr;
+
+pub(crate) fn module() -> PathBuf {
+    PathBuf::from("/sys/module/xe")
+}
+
+pub(crate) fn tile(card: u64, tile: u64) -> PathBuf {
+    device_attr(card, &format!("tile{}", tile))
+}
@@ -0,0 +1,84 @@
+use futures::stream::{Stream, TryStreamExt as _};
+use futures::Future;
+
+use crate::xe;
+#[cfg(feature = "cache")]
+use crate::xe::Cache;
+use crate::Result;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Values {
+    id: u64,
+}
+
+impl Values {
+    pub fn available() -> impl Future<Output = Result<bool>> {
+        xe::available()
+    }
+
+    pub fn exists(id: u64) -> impl Future<Output = Result<bool>> {
+        xe::exists(id)
+    }
+
+    pub fn ids() -> impl Stream<Item = Result<u64>> {
+        xe::ids()
+    }
+
+    pub fn all() -> impl Stream<Item = Result<Self>> {
+        xe::ids().map_ok(Self::new)
+    }
+
+    pub fn new(id: u64) -> Self {
+        Self { id }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn act_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        xe::act_freq_mhz(self.id)
+    }
+
+    pub fn cur_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        xe::cur_freq_mhz(self.id)
+    }
+
+    pub fn max_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        xe::max_freq_mhz(self.id)
+    }
+
+    pub fn min_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        xe::min_freq_mhz(self.id)
+    }
+
+    pub fn rp0_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        xe::rp0_freq_mhz(self.id)
+    }
+
+    pub fn rpn_freq_mhz(&self) -> impl Future<Output = Result<u64>> {
+        xe::rpn_freq_mhz(self.id)
+    }
+
+    pub fn set_max_freq_mhz(&self, v: u64) -> impl Future<Output = Result<()>> {
+        xe::set_max_freq_mhz(self.id, v)
+    }
+
+    pub fn set_min_freq_mhz(&self, v: u64) -> impl Future<Output = Result<()>> {
+        xe::set_min_freq_mhz(self.id, v)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<Cache> for Values {
+    fn from(v: Cache) -> Self {
+        Self::new(v.id())
+    }
+}
+
+#[cfg(feature = "cache")]
+impl From<&Cache> for Values {
+    fn from(v: &Cache) -> Self {
+        Self::new(v.id())
+    }
+}